@@ -0,0 +1,56 @@
+﻿using BenchmarkDotNet.Attributes;
+using System.Numerics;
+
+namespace NiTiS.Math.Benchmarks;
+
+/// <summary>
+/// Compares the accelerated <see cref="Vector4D{T}" /> and <see cref="Mat4{T}" /> paths against <see cref="Vector4" /> and <see cref="Matrix4x4" />.
+/// </summary>
+[MemoryDiagnoser]
+[CategoriesColumn]
+[GroupBenchmarksBy(BenchmarkDotNet.Configs.BenchmarkLogicalGroupRule.ByCategory)]
+public class VectorBenchmarks
+{
+	private Vector4 left = new(1f, 2f, 3f, 4f);
+	private Vector4 right = new(-4f, 3f, 0.5f, 8f);
+	private Vector4D<float> leftD = new(1f, 2f, 3f, 4f);
+	private Vector4D<float> rightD = new(-4f, 3f, 0.5f, 8f);
+	private Matrix4x4 matrix = Matrix4x4.CreateFromYawPitchRoll(0.3f, 0.6f, 0.9f) * Matrix4x4.CreateTranslation(1f, 2f, 3f);
+	private Mat4<float> matrixD = Mat4.CreateFromYawPitchRoll(Angle.FromRadians(0.3f), Angle.FromRadians(0.6f), Angle.FromRadians(0.9f)) * Mat4.CreateTranslation(new Vector3D<float>(1f, 2f, 3f));
+	private float amount = 0.25f;
+
+	[Benchmark(Baseline = true), BenchmarkCategory("Dot")]
+	public float DotSystem() => Vector4.Dot(left, right);
+	[Benchmark, BenchmarkCategory("Dot")]
+	public float DotNiTiS() => Vector4D.Dot(leftD, rightD);
+
+	[Benchmark(Baseline = true), BenchmarkCategory("Length")]
+	public float LengthSystem() => left.Length();
+	[Benchmark, BenchmarkCategory("Length")]
+	public float LengthNiTiS() => Vector4D.Length(leftD);
+
+	[Benchmark(Baseline = true), BenchmarkCategory("Normalize")]
+	public Vector4 NormalizeSystem() => Vector4.Normalize(left);
+	[Benchmark, BenchmarkCategory("Normalize")]
+	public Vector4D<float> NormalizeNiTiS() => Vector4D.Normalize(leftD);
+
+	[Benchmark(Baseline = true), BenchmarkCategory("Min")]
+	public Vector4 MinSystem() => Vector4.Min(left, right);
+	[Benchmark, BenchmarkCategory("Min")]
+	public Vector4D<float> MinNiTiS() => Vector4D.Min(leftD, rightD);
+
+	[Benchmark(Baseline = true), BenchmarkCategory("Max")]
+	public Vector4 MaxSystem() => Vector4.Max(left, right);
+	[Benchmark, BenchmarkCategory("Max")]
+	public Vector4D<float> MaxNiTiS() => Vector4D.Max(leftD, rightD);
+
+	[Benchmark(Baseline = true), BenchmarkCategory("Lerp")]
+	public Vector4 LerpSystem() => Vector4.Lerp(left, right, amount);
+	[Benchmark, BenchmarkCategory("Lerp")]
+	public Vector4D<float> LerpNiTiS() => Vector4D.Lerp(leftD, rightD, amount);
+
+	[Benchmark(Baseline = true), BenchmarkCategory("Multiply")]
+	public Matrix4x4 MultiplySystem() => matrix * matrix;
+	[Benchmark, BenchmarkCategory("Multiply")]
+	public Mat4<float> MultiplyNiTiS() => matrixD * matrixD;
+}
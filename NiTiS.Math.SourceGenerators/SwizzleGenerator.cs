@@ -0,0 +1,82 @@
+﻿using Microsoft.CodeAnalysis;
+using System.Text;
+
+namespace NiTiS.Math.SourceGenerators;
+
+/// <summary>
+/// Emits swizzle properties (<c>XY</c>, <c>ZYX</c>, <c>WZYX</c>, ...) for every 2, 3 and 4 component combination of the vector structs.
+/// </summary>
+[Generator(LanguageNames.CSharp)]
+public sealed class SwizzleGenerator : IIncrementalGenerator
+{
+	private static readonly string[] VectorTypes = { "Vector2D", "Vector3D", "Vector4D" };
+	private static readonly char[] Components = { 'X', 'Y', 'Z', 'W' };
+
+	public void Initialize(IncrementalGeneratorInitializationContext context)
+	{
+		context.RegisterPostInitializationOutput(static context =>
+		{
+			for (int dimension = 2; dimension <= 4; dimension++)
+			{
+				string type = VectorTypes[dimension - 2];
+				context.AddSource($"{type}.Swizzle.g.cs", Generate(type, dimension));
+			}
+		});
+	}
+
+	private static string Generate(string type, int dimension)
+	{
+		StringBuilder sb = new();
+
+		sb.AppendLine("// <auto-generated />");
+		sb.AppendLine("#nullable enable");
+		sb.AppendLine();
+		sb.AppendLine("namespace NiTiS.Math;");
+		sb.AppendLine();
+		sb.Append("public readonly partial struct ").Append(type).AppendLine("<T>");
+		sb.AppendLine("{");
+
+		char[] swizzle = new char[4];
+		for (int length = 2; length <= 4; length++)
+			AppendSwizzles(sb, swizzle, 0, length, dimension);
+
+		sb.AppendLine("}");
+		return sb.ToString();
+	}
+
+	private static void AppendSwizzles(StringBuilder sb, char[] swizzle, int index, int length, int dimension)
+	{
+		if (index == length)
+		{
+			AppendProperty(sb, swizzle, length);
+			return;
+		}
+
+		for (int i = 0; i < dimension; i++)
+		{
+			swizzle[index] = Components[i];
+			AppendSwizzles(sb, swizzle, index + 1, length, dimension);
+		}
+	}
+
+	private static void AppendProperty(StringBuilder sb, char[] swizzle, int length)
+	{
+		string name = new(swizzle, 0, length);
+
+		sb.AppendLine("\t[global::System.Diagnostics.DebuggerBrowsable(global::System.Diagnostics.DebuggerBrowsableState.Never)]");
+		sb.Append("\tpublic ").Append(VectorTypes[length - 2]).Append("<T> ").AppendLine(name);
+		sb.AppendLine("\t{");
+		sb.AppendLine("\t\t[global::System.Runtime.CompilerServices.MethodImpl(global::System.Runtime.CompilerServices.MethodImplOptions.AggressiveInlining)]");
+		sb.Append("\t\tget => new(");
+
+		for (int i = 0; i < length; i++)
+		{
+			if (i != 0)
+				sb.Append(", ");
+			sb.Append(swizzle[i]);
+		}
+
+		sb.AppendLine(");");
+		sb.AppendLine("\t}");
+	}
+}
@@ -0,0 +1,128 @@
+﻿using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class Angle
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Abs<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Abs(angle.Radians));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Acos<T>(T x)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Acos(x));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Asin<T>(T x)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Asin(x));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Atan<T>(T x)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Atan(x));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Atan2<T>(T y, T x)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Atan2(y, x));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Clamp<T>(Angle<T> angle, Angle<T> min, Angle<T> max)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Clamp(angle.Radians, min.Radians, max.Radians));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Cos<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> T.Cos(angle.Radians);
+
+	/// <summary>Shortest signed rotation from <paramref name="from" /> to <paramref name="to" />, in (-pi, pi].</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Difference<T>(Angle<T> from, Angle<T> to)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> WrapSigned(to - from);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> FromDegrees<T>(T degrees)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromDegrees(degrees);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> FromRadians<T>(T radians)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(radians);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> FromTurns<T>(T turns)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromTurns(turns);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Lerp<T>(Angle<T> left, Angle<T> right, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left * (T.One - amount) + right * amount;
+
+	/// <summary>Interpolates along the shortest arc between two angles.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> LerpShortest<T>(Angle<T> left, Angle<T> right, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left + Difference(left, right) * amount;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Max<T>(Angle<T> left, Angle<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Max(left.Radians, right.Radians));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> Min<T>(Angle<T> left, Angle<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Angle<T>.FromRadians(T.Min(left.Radians, right.Radians));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Sin<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> T.Sin(angle.Radians);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static (T Sin, T Cos) SinCos<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> T.SinCos(angle.Radians);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Tan<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> T.Tan(angle.Radians);
+
+	/// <summary>Wraps the angle to [0, 2pi).</summary>
+	public static Angle<T> Wrap<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T radians = angle.Radians % T.Tau;
+
+		if (radians < T.Zero)
+			radians += T.Tau;
+
+		// Adding tau to a tiny negative remainder rounds up to tau itself
+		if (radians >= T.Tau)
+			radians = T.Zero;
+
+		return Angle<T>.FromRadians(radians);
+	}
+
+	/// <summary>Wraps the angle to (-pi, pi].</summary>
+	public static Angle<T> WrapSigned<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T radians = Wrap(angle).Radians;
+
+		if (radians > T.Pi)
+			radians -= T.Tau;
+
+		return Angle<T>.FromRadians(radians);
+	}
+}
@@ -0,0 +1,135 @@
+﻿using System;
+using System.Diagnostics;
+using System.Diagnostics.CodeAnalysis;
+using System.Globalization;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Angle stored in radians, created through <see cref="FromRadians" />, <see cref="FromDegrees" /> or <see cref="FromTurns" /> so the unit is always explicit.
+/// </summary>
+[DebuggerDisplay($@"{{{nameof(ToString)}(""G""),nq}}")]
+public readonly struct Angle<T> :
+	// Angle op Angle
+	IAdditionOperators<Angle<T>, Angle<T>, Angle<T>>,
+	ISubtractionOperators<Angle<T>, Angle<T>, Angle<T>>,
+	IDivisionOperators<Angle<T>, Angle<T>, T>,
+	IModulusOperators<Angle<T>, Angle<T>, Angle<T>>,
+	IEqualityOperators<Angle<T>, Angle<T>, bool>,
+	IComparisonOperators<Angle<T>, Angle<T>, bool>,
+	// Angle op T
+	IMultiplyOperators<Angle<T>, T, Angle<T>>,
+	IDivisionOperators<Angle<T>, T, Angle<T>>,
+	// Unary op
+	IUnaryNegationOperators<Angle<T>, Angle<T>>,
+	IUnaryPlusOperators<Angle<T>, Angle<T>>,
+	IFormattable,
+	IEquatable<Angle<T>>,
+	IComparable<Angle<T>>,
+	IComparable
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	public readonly T Radians;
+	public readonly T Degrees
+	{
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		get => Radians * (T.CreateTruncating(180) / T.Pi);
+	}
+	public readonly T Turns
+	{
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		get => Radians / T.Tau;
+	}
+
+	private Angle(T radians)
+		=> Radians = radians;
+	public static Angle<T> Zero => new(T.Zero);
+	public static Angle<T> RightAngle => new(T.Pi / Scalar<T>.Two);
+	public static Angle<T> HalfTurn => new(T.Pi);
+	public static Angle<T> FullTurn => new(T.Tau);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> FromRadians(T radians)
+		=> new(radians);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> FromDegrees(T degrees)
+		=> new(degrees * (T.Pi / T.CreateTruncating(180)));
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> FromTurns(T turns)
+		=> new(turns * T.Tau);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator +(Angle<T> left, Angle<T> right)
+		=> new(left.Radians + right.Radians);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator -(Angle<T> left, Angle<T> right)
+		=> new(left.Radians - right.Radians);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T operator /(Angle<T> left, Angle<T> right)
+		=> left.Radians / right.Radians;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator %(Angle<T> left, Angle<T> right)
+		=> new(left.Radians % right.Radians);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(Angle<T> left, Angle<T> right)
+		=> left.Radians == right.Radians;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(Angle<T> left, Angle<T> right)
+		=> left.Radians != right.Radians;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator <(Angle<T> left, Angle<T> right)
+		=> left.Radians < right.Radians;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator >(Angle<T> left, Angle<T> right)
+		=> left.Radians > right.Radians;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator <=(Angle<T> left, Angle<T> right)
+		=> left.Radians <= right.Radians;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator >=(Angle<T> left, Angle<T> right)
+		=> left.Radians >= right.Radians;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator *(Angle<T> left, T right)
+		=> new(left.Radians * right);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator *(T left, Angle<T> right)
+		=> new(left * right.Radians);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator /(Angle<T> left, T right)
+		=> new(left.Radians / right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator -(Angle<T> operand)
+		=> new(-operand.Radians);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Angle<T> operator +(Angle<T> operand)
+		=> operand;
+
+	public readonly int CompareTo(Angle<T> other)
+		=> Radians.CompareTo(other.Radians);
+	public readonly int CompareTo(object? obj)
+		=> obj switch
+		{
+			null => 1,
+			Angle<T> angle => CompareTo(angle),
+			_ => throw new ArgumentException($"Object must be of type {nameof(Angle<T>)}", nameof(obj)),
+		};
+
+	public override readonly int GetHashCode()
+		=> Radians.GetHashCode();
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Angle<T> angle
+		? angle == this : false;
+	public readonly bool Equals(Angle<T> other)
+		=> Radians.Equals(other.Radians);
+	public override readonly string ToString() => ToString("G", CultureInfo.CurrentCulture);
+	public readonly string ToString(string? format) => ToString(format, CultureInfo.CurrentCulture);
+	/// <summary>Formats the angle in radians with a <c>rad</c> suffix.</summary>
+	public readonly string ToString(string? format, IFormatProvider? formatProvider)
+		=> Radians.ToString(format, formatProvider) + " rad";
+}
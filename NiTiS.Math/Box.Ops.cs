@@ -1,16 +1,39 @@
-﻿using System.Numerics;
+﻿using System;
+using System.Numerics;
 using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
 
 public static class Box
 {
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool ApproximatelyEquals<T>(Box<T> left, Box<T> right, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Vector3D.ApproximatelyEquals(left.Min, right.Min, tolerance)
+		&& Vector3D.ApproximatelyEquals(left.Max, right.Max, tolerance);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Box<T> CreateInflated<T>(Box<T> square, Vector3D<T> point)
 		where T : unmanaged, INumber<T>
 		=> new(Vector3D.Min(square.Min, point), Vector3D.Max(square.Max, point));
 
+	/// <summary>Returns the tightest axis-aligned box that contains every point.</summary>
+	public static Box<T> FromPoints<T>(ReadOnlySpan<Vector3D<T>> points)
+		where T : unmanaged, INumber<T>
+	{
+		if (points.IsEmpty)
+			throw new ArgumentException("Point span must not be empty", nameof(points));
+
+		Span<T> min = stackalloc T[3];
+		Span<T> max = stackalloc T[3];
+
+		SpanMath.MinMax(MemoryMarshal.Cast<Vector3D<T>, T>(points), min, max);
+
+		return new(min[0], min[1], min[2], max[0], max[1], max[2]);
+	}
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T GetDistanceToNearestEdge<T>(Box<T> box, Vector3D<T> point)
 		where T : unmanaged, INumber<T>, IRootFunctions<T>
@@ -24,4 +47,58 @@ public static class Box
 		T dz = T.Max(T.Max(box.Min.Z - point.Z, T.Zero), point.Z - box.Max.Y);
 		return (dx * dx) + (dy * dy) + (dz * dz);
 	}
+
+	/// <summary>Returns the tightest axis-aligned box that contains <paramref name="box" /> transformed by <paramref name="matrix" />.</summary>
+	/// <remarks>
+	/// For a projective <paramref name="matrix" /> the box must lie entirely in front of the projection, every corner must have a positive transformed W.
+	/// A box crossing W = 0 has an unbounded image and the result is meaningless, clip it against the near plane first.
+	/// </remarks>
+	public static Box<T> Transform<T>(Box<T> box, Mat4<T> matrix)
+		where T : unmanaged, INumber<T>
+	{
+		if (!matrix.IsAffine)
+		{
+			// Projective matrices do not preserve parallel edges, so every corner has to be transformed
+			Vector3D<T> first = Vector3D.Transform(box.Min, matrix, true);
+			Box<T> result = new(first, first);
+
+			for (int corner = 1; corner < 8; corner++)
+			{
+				Vector3D<T> point = new(
+					(corner & 1) == 0 ? box.Min.X : box.Max.X,
+					(corner & 2) == 0 ? box.Min.Y : box.Max.Y,
+					(corner & 4) == 0 ? box.Min.Z : box.Max.Z
+					);
+				result = CreateInflated(result, Vector3D.Transform(point, matrix, true));
+			}
+
+			return result;
+		}
+
+		T minX = matrix.M41, maxX = matrix.M41;
+		T minY = matrix.M42, maxY = matrix.M42;
+		T minZ = matrix.M43, maxZ = matrix.M43;
+
+		Accumulate(matrix.M11, box.Min.X, box.Max.X, ref minX, ref maxX);
+		Accumulate(matrix.M21, box.Min.Y, box.Max.Y, ref minX, ref maxX);
+		Accumulate(matrix.M31, box.Min.Z, box.Max.Z, ref minX, ref maxX);
+
+		Accumulate(matrix.M12, box.Min.X, box.Max.X, ref minY, ref maxY);
+		Accumulate(matrix.M22, box.Min.Y, box.Max.Y, ref minY, ref maxY);
+		Accumulate(matrix.M32, box.Min.Z, box.Max.Z, ref minY, ref maxY);
+
+		Accumulate(matrix.M13, box.Min.X, box.Max.X, ref minZ, ref maxZ);
+		Accumulate(matrix.M23, box.Min.Y, box.Max.Y, ref minZ, ref maxZ);
+		Accumulate(matrix.M33, box.Min.Z, box.Max.Z, ref minZ, ref maxZ);
+
+		return new(minX, minY, minZ, maxX, maxY, maxZ);
+
+		static void Accumulate(T factor, T min, T max, ref T resultMin, ref T resultMax)
+		{
+			T a = factor * min;
+			T b = factor * max;
+			resultMin += T.Min(a, b);
+			resultMax += T.Max(a, b);
+		}
+	}
 }
\ No newline at end of file
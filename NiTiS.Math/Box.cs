@@ -1,9 +1,18 @@
 ﻿using System;
+using System.Diagnostics;
 using System.Diagnostics.CodeAnalysis;
+using System.Globalization;
 using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 
 namespace NiTiS.Math;
+[DebuggerDisplay($@"{{{nameof(ToString)}(""G""),nq}}")]
 public readonly struct Box<T> :
+	ISpanFormattable,
+#if NET8_0_OR_GREATER
+	IUtf8SpanFormattable,
+#endif
 	IEquatable<Box<T>>,
 	IEqualityOperators<Box<T>, Box<T>, bool>
 	where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
@@ -22,6 +31,19 @@ public readonly struct Box<T> :
 	public Box(T minX, T minY, T minZ, Vector3D<T> max)
 		=> (Min, Max) = (new(minX, minY, minZ), max);
 
+	/// <summary>Converts every component, throwing <see cref="OverflowException" /> when it is not representable.</summary>
+	public static Box<T> CreateChecked<TOther>(Box<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>, IComparisonOperators<TOther, TOther, bool>
+		=> new(Vector3D<T>.CreateChecked(value.Min), Vector3D<T>.CreateChecked(value.Max));
+	/// <summary>Converts every component, clamping values that are not representable.</summary>
+	public static Box<T> CreateSaturating<TOther>(Box<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>, IComparisonOperators<TOther, TOther, bool>
+		=> new(Vector3D<T>.CreateSaturating(value.Min), Vector3D<T>.CreateSaturating(value.Max));
+	/// <summary>Converts every component, truncating values that are not representable.</summary>
+	public static Box<T> CreateTruncating<TOther>(Box<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>, IComparisonOperators<TOther, TOther, bool>
+		=> new(Vector3D<T>.CreateTruncating(value.Min), Vector3D<T>.CreateTruncating(value.Max));
+
 	public readonly bool Equals(Box<T> other)
 		=> this == other;
 	public override readonly bool Equals([NotNullWhen(true)] object? obj)
@@ -49,4 +71,16 @@ public readonly struct Box<T> :
 	public static bool operator !=(Box<T> left, Box<T> right)
 		=> left.Min != right.Min
 		|| left.Max != right.Max;
+
+	public override readonly string ToString() => ToString("G", CultureInfo.CurrentCulture);
+	public readonly string ToString(string? format) => ToString(format, CultureInfo.CurrentCulture);
+	/// <summary>Formats the bounds as the minimum and maximum corners, <c>[&lt;min&gt; &lt;max&gt;]</c>.</summary>
+	public readonly string ToString(string? format, IFormatProvider? formatProvider)
+		=> Formatting.ToString(this, format, formatProvider);
+	public readonly bool TryFormat(Span<char> destination, out int charsWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatRows(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in Min.X), 6), 3, destination, out charsWritten, format, provider);
+#if NET8_0_OR_GREATER
+	public readonly bool TryFormat(Span<byte> utf8Destination, out int bytesWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatRows(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in Min.X), 6), 3, utf8Destination, out bytesWritten, format, provider);
+#endif
 }
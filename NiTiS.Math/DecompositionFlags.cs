@@ -0,0 +1,20 @@
+﻿using System;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Describes what was found while decomposing a matrix into scale, rotation and translation.
+/// </summary>
+[Flags]
+public enum DecompositionFlags
+{
+	None = 0,
+	/// <summary>The matrix mirrors space; the X scale was negated to keep the rotation proper.</summary>
+	NegativeScale = 1 << 0,
+	/// <summary>The matrix axes are not orthogonal; the rotation was orthonormalized and loses the shear.</summary>
+	Shear = 1 << 1,
+	/// <summary>At least one axis has (almost) zero length; the rotation is the identity.</summary>
+	Degenerate = 1 << 2,
+	/// <summary>The last column is not (0, 0, 0, 1); the projective part is ignored.</summary>
+	Projective = 1 << 3,
+}
@@ -0,0 +1,161 @@
+﻿using System;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class DualQuaternion
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> Add<T>(DualQuaternion<T> left, DualQuaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left + right;
+
+	/// <summary>
+	/// Dual quaternion linear blending, the weighted sum of the transforms is normalized.
+	/// Transforms are aligned to the hemisphere of the first one so blending takes the shortest path.
+	/// </summary>
+	public static DualQuaternion<T> Blend<T>(ReadOnlySpan<DualQuaternion<T>> transforms, ReadOnlySpan<T> weights)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		if (transforms.Length != weights.Length)
+			throw new ArgumentException("Weight count must be equal to transform count", nameof(weights));
+		if (transforms.IsEmpty)
+			return DualQuaternion<T>.Identity;
+
+		Quaternion<T> pivot = transforms[0].Real;
+		DualQuaternion<T> sum = DualQuaternion<T>.Zero;
+
+		for (int i = 0; i < transforms.Length; i++)
+		{
+			T weight = Quaternion.Dot(pivot, transforms[i].Real) < T.Zero
+				? -weights[i]
+				: weights[i];
+
+			sum += transforms[i] * weight;
+		}
+
+		return Normalize(sum);
+	}
+
+	/// <summary>Concatenates two transforms, the result applies <paramref name="first" /> and then <paramref name="second" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> Concatenate<T>(DualQuaternion<T> first, DualQuaternion<T> second)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> second * first;
+
+	/// <summary>Conjugates both parts, for a unit dual quaternion this is the inverse transform.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> Conjugate<T>(DualQuaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(Quaternion.Conjugate(value.Real), Quaternion.Conjugate(value.Dual));
+
+	/// <summary>Creates a transform that rotates and then translates.</summary>
+	/// <param name="rotation">Normalized rotation.</param>
+	public static DualQuaternion<T> Create<T>(Quaternion<T> rotation, Vector3D<T> translation)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T half = T.One / Scalar<T>.Two;
+
+		return new(rotation, new Quaternion<T>(translation * half, T.Zero) * rotation);
+	}
+
+	/// <summary>Creates a transform from the rotation and translation of the given rigid matrix.</summary>
+	public static DualQuaternion<T> CreateFromMatrix<T>(Mat4<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Create(Quaternion.Normalize(Quaternion.CreateFromRotationMatrix(matrix)), matrix.Translation);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> CreateFromRotation<T>(Quaternion<T> rotation)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(rotation, Quaternion<T>.Zero);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> CreateFromTranslation<T>(Vector3D<T> translation)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Create(Quaternion<T>.Identity, translation);
+
+	/// <summary>Gets the translation of a unit dual quaternion.</summary>
+	public static Vector3D<T> GetTranslation<T>(DualQuaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Quaternion<T> translation = value.Dual * Quaternion.Conjugate(value.Real);
+		return translation.VectorPart * Scalar<T>.Two;
+	}
+
+	public static DualQuaternion<T> Inverse<T>(DualQuaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Quaternion<T> real = Quaternion.Inverse(value.Real);
+		return new(real, -(real * value.Dual * real));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> Multiply<T>(DualQuaternion<T> left, DualQuaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left * right;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> Multiply<T>(DualQuaternion<T> left, T right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left * right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> Negate<T>(DualQuaternion<T> operand)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> -operand;
+
+	/// <summary>Scales the dual quaternion to unit length and removes the dual component that does not describe a rigid transform.</summary>
+	public static DualQuaternion<T> Normalize<T>(DualQuaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T inverseLength = T.One / Quaternion.Length(value.Real);
+
+		Quaternion<T> real = value.Real * inverseLength;
+		Quaternion<T> dual = value.Dual * inverseLength;
+
+		return new(real, dual - real * Quaternion.Dot(real, dual));
+	}
+
+	/// <summary>Screw linear interpolation between two unit dual quaternions along the shortest path.</summary>
+	public static DualQuaternion<T> ScLerp<T>(DualQuaternion<T> left, DualQuaternion<T> right, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		if (Quaternion.Dot(left.Real, right.Real) < T.Zero)
+			right = -right;
+
+		DualQuaternion<T> difference = Conjugate(left) * right;
+		Quaternion<T> real = difference.Real, dual = difference.Dual;
+
+		Vector3D<T> axis = real.VectorPart;
+		T sinHalf = Vector3D.Length(axis);
+
+		// Pure translation has no screw axis, scale the translation only
+		if (!(sinHalf > T.CreateTruncating(1e-6)))
+			return left * new DualQuaternion<T>(Quaternion<T>.Identity, dual * amount);
+
+		T half = T.Atan2(sinHalf, real.W);
+		axis /= sinHalf;
+
+		// Screw parameters: half angle, half pitch along the axis and the axis moment
+		T halfPitch = -dual.W / sinHalf;
+		Vector3D<T> moment = (dual.VectorPart - axis * (halfPitch * real.W)) / sinHalf;
+
+		half *= amount;
+		halfPitch *= amount;
+
+		T sin = T.Sin(half), cos = T.Cos(half);
+
+		DualQuaternion<T> power = new(
+			new Quaternion<T>(axis * sin, cos),
+			new Quaternion<T>(moment * sin + axis * (halfPitch * cos), -halfPitch * sin)
+			);
+
+		return left * power;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> Subtract<T>(DualQuaternion<T> left, DualQuaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left - right;
+}
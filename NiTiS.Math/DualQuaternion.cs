@@ -0,0 +1,93 @@
+﻿using System;
+using System.Diagnostics;
+using System.Diagnostics.CodeAnalysis;
+using System.Globalization;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Dual quaternion <c>Real + e * Dual</c>, a unit dual quaternion represents a rigid transform.
+/// </summary>
+/// <remarks>
+/// Like <see cref="Quaternion{T}" />, <c>left * right</c> applies <c>right</c> first and <c>left</c> second.
+/// </remarks>
+[DebuggerDisplay($@"{{{nameof(ToString)}(""G""),nq}}")]
+public readonly struct DualQuaternion<T> :
+	// DualQuaternion op DualQuaternion
+	IAdditionOperators<DualQuaternion<T>, DualQuaternion<T>, DualQuaternion<T>>,
+	ISubtractionOperators<DualQuaternion<T>, DualQuaternion<T>, DualQuaternion<T>>,
+	IMultiplyOperators<DualQuaternion<T>, DualQuaternion<T>, DualQuaternion<T>>,
+	IEqualityOperators<DualQuaternion<T>, DualQuaternion<T>, bool>,
+	// DualQuaternion op T
+	IMultiplyOperators<DualQuaternion<T>, T, DualQuaternion<T>>,
+	// Unary op
+	IUnaryNegationOperators<DualQuaternion<T>, DualQuaternion<T>>,
+	IUnaryPlusOperators<DualQuaternion<T>, DualQuaternion<T>>,
+	IFormattable,
+	IEquatable<DualQuaternion<T>>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	/// <summary>Rotation part.</summary>
+	public readonly Quaternion<T> Real;
+	/// <summary>Translation part, equal to half the translation multiplied by <see cref="Real" />.</summary>
+	public readonly Quaternion<T> Dual;
+
+	public DualQuaternion(Quaternion<T> real, Quaternion<T> dual)
+		=> (Real, Dual) = (real, dual);
+	public static DualQuaternion<T> Identity => new(Quaternion<T>.Identity, Quaternion<T>.Zero);
+	public static DualQuaternion<T> Zero => new(Quaternion<T>.Zero, Quaternion<T>.Zero);
+
+	/// <summary>Indicates whether the current dual quaternion is the identity transform.</summary>
+	public readonly bool IsIdentity
+		=> Real.IsIdentity && Dual == Quaternion<T>.Zero;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> operator +(DualQuaternion<T> left, DualQuaternion<T> right)
+		=> new(left.Real + right.Real, left.Dual + right.Dual);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> operator -(DualQuaternion<T> left, DualQuaternion<T> right)
+		=> new(left.Real - right.Real, left.Dual - right.Dual);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> operator *(DualQuaternion<T> left, DualQuaternion<T> right)
+		=> new(
+			left.Real * right.Real,
+			left.Real * right.Dual + left.Dual * right.Real
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(DualQuaternion<T> left, DualQuaternion<T> right)
+		=> left.Real == right.Real
+		&& left.Dual == right.Dual;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(DualQuaternion<T> left, DualQuaternion<T> right)
+		=> !(left == right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> operator *(DualQuaternion<T> left, T right)
+		=> new(left.Real * right, left.Dual * right);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> operator *(T left, DualQuaternion<T> right)
+		=> right * left;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> operator -(DualQuaternion<T> operand)
+		=> new(-operand.Real, -operand.Dual);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static DualQuaternion<T> operator +(DualQuaternion<T> operand)
+		=> operand;
+
+	public override readonly int GetHashCode()
+		=> HashCode.Combine(Real, Dual);
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is DualQuaternion<T> quat
+		? quat == this : false;
+	public readonly bool Equals(DualQuaternion<T> other)
+		=> this == other;
+	public override readonly string ToString() => ToString("G", CultureInfo.CurrentCulture);
+	public readonly string ToString(string? format) => ToString(format, CultureInfo.CurrentCulture);
+	public readonly string ToString(string? format, IFormatProvider? formatProvider)
+		=> $"[{Real.ToString(format, formatProvider)} {Dual.ToString(format, formatProvider)}]";
+}
@@ -0,0 +1,12 @@
+﻿namespace NiTiS.Math;
+
+/// <summary>
+/// Describes which axes Euler angles rotate around.
+/// </summary>
+public enum EulerFrame
+{
+	/// <summary>Every rotation is around a fixed world axis.</summary>
+	Extrinsic,
+	/// <summary>Every rotation is around an axis of the frame produced by the previous rotations.</summary>
+	Intrinsic,
+}
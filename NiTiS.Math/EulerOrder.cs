@@ -0,0 +1,21 @@
+﻿namespace NiTiS.Math;
+
+/// <summary>
+/// Sequence of axes used by Euler angles, in the order the rotations are applied.
+/// Tait-Bryan orders use three distinct axes, proper Euler orders repeat the first axis.
+/// </summary>
+public enum EulerOrder
+{
+	XYZ,
+	XZY,
+	YXZ,
+	YZX,
+	ZXY,
+	ZYX,
+	XYX,
+	XZX,
+	YXY,
+	YZY,
+	ZXZ,
+	ZYZ,
+}
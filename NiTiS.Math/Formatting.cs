@@ -0,0 +1,197 @@
+﻿using System;
+using System.Buffers;
+using System.Globalization;
+using System.Numerics;
+#if NET8_0_OR_GREATER
+using System.Text;
+#endif
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Span based formatting shared by vectors (<c>&lt;x, y&gt;</c>) and by matrices and bounds written as rows of vectors (<c>[&lt;a, b&gt; &lt;c, d&gt;]</c>).
+/// </summary>
+internal static class Formatting
+{
+	private const int StackBufferSize = 128;
+
+	/// <summary>Formats the value through its <see cref="ISpanFormattable.TryFormat" /> without intermediate allocations.</summary>
+	public static string ToString<TValue>(in TValue value, string? format, IFormatProvider? provider)
+		where TValue : struct, ISpanFormattable
+	{
+		Span<char> buffer = stackalloc char[StackBufferSize];
+
+		if (value.TryFormat(buffer, out int charsWritten, format, provider))
+			return new string(buffer[..charsWritten]);
+
+		for (int size = StackBufferSize * 4; ; size *= 2)
+		{
+			char[] array = ArrayPool<char>.Shared.Rent(size);
+
+			try
+			{
+				if (value.TryFormat(array, out charsWritten, format, provider))
+					return new string(array, 0, charsWritten);
+			}
+			finally
+			{
+				ArrayPool<char>.Shared.Return(array);
+			}
+		}
+	}
+
+	public static bool TryFormatVector<T>(ReadOnlySpan<T> components, Span<char> destination, out int charsWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		where T : unmanaged, INumberBase<T>
+	{
+		charsWritten = 0;
+		return TryWriteVector(components, destination, ref charsWritten, format, provider);
+	}
+	public static bool TryFormatRows<T>(ReadOnlySpan<T> values, int columns, Span<char> destination, out int charsWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		where T : unmanaged, INumberBase<T>
+	{
+		int written = 0;
+
+		if (!TryWrite('[', destination, ref written))
+			return Fail(out charsWritten);
+
+		for (int row = 0; row * columns < values.Length; row++)
+		{
+			if (row != 0 && !TryWrite(' ', destination, ref written))
+				return Fail(out charsWritten);
+
+			if (!TryWriteVector(values.Slice(row * columns, columns), destination, ref written, format, provider))
+				return Fail(out charsWritten);
+		}
+
+		if (!TryWrite(']', destination, ref written))
+			return Fail(out charsWritten);
+
+		charsWritten = written;
+		return true;
+	}
+
+	private static bool TryWriteVector<T>(ReadOnlySpan<T> components, Span<char> destination, ref int written, ReadOnlySpan<char> format, IFormatProvider? provider)
+		where T : unmanaged, INumberBase<T>
+	{
+		string separator = NumberFormatInfo.GetInstance(provider).NumberGroupSeparator;
+		bool space = !string.IsNullOrWhiteSpace(separator);
+		int start = written;
+
+		if (!TryWrite('<', destination, ref written))
+			return Reset(ref written, start);
+
+		for (int i = 0; i < components.Length; i++)
+		{
+			if (i != 0)
+			{
+				if (!separator.AsSpan().TryCopyTo(destination[written..]))
+					return Reset(ref written, start);
+				written += separator.Length;
+				if (space && !TryWrite(' ', destination, ref written))
+					return Reset(ref written, start);
+			}
+
+			if (!components[i].TryFormat(destination[written..], out int componentWritten, format, provider))
+				return Reset(ref written, start);
+			written += componentWritten;
+		}
+
+		if (!TryWrite('>', destination, ref written))
+			return Reset(ref written, start);
+
+		return true;
+	}
+
+	private static bool TryWrite(char value, Span<char> destination, ref int written)
+	{
+		if (written >= destination.Length)
+			return false;
+
+		destination[written++] = value;
+		return true;
+	}
+
+	private static bool Fail(out int written)
+	{
+		written = 0;
+		return false;
+	}
+	private static bool Reset(ref int written, int start)
+	{
+		written = start;
+		return false;
+	}
+
+#if NET8_0_OR_GREATER
+	public static bool TryFormatVector<T>(ReadOnlySpan<T> components, Span<byte> utf8Destination, out int bytesWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		where T : unmanaged, INumberBase<T>
+	{
+		bytesWritten = 0;
+		return TryWriteVector(components, utf8Destination, ref bytesWritten, format, provider);
+	}
+	public static bool TryFormatRows<T>(ReadOnlySpan<T> values, int columns, Span<byte> utf8Destination, out int bytesWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		where T : unmanaged, INumberBase<T>
+	{
+		int written = 0;
+
+		if (!TryWrite((byte)'[', utf8Destination, ref written))
+			return Fail(out bytesWritten);
+
+		for (int row = 0; row * columns < values.Length; row++)
+		{
+			if (row != 0 && !TryWrite((byte)' ', utf8Destination, ref written))
+				return Fail(out bytesWritten);
+
+			if (!TryWriteVector(values.Slice(row * columns, columns), utf8Destination, ref written, format, provider))
+				return Fail(out bytesWritten);
+		}
+
+		if (!TryWrite((byte)']', utf8Destination, ref written))
+			return Fail(out bytesWritten);
+
+		bytesWritten = written;
+		return true;
+	}
+
+	private static bool TryWriteVector<T>(ReadOnlySpan<T> components, Span<byte> destination, ref int written, ReadOnlySpan<char> format, IFormatProvider? provider)
+		where T : unmanaged, INumberBase<T>
+	{
+		string separator = NumberFormatInfo.GetInstance(provider).NumberGroupSeparator;
+		bool space = !string.IsNullOrWhiteSpace(separator);
+		int start = written;
+
+		if (!TryWrite((byte)'<', destination, ref written))
+			return Reset(ref written, start);
+
+		for (int i = 0; i < components.Length; i++)
+		{
+			if (i != 0)
+			{
+				if (!Encoding.UTF8.TryGetBytes(separator, destination[written..], out int separatorWritten))
+					return Reset(ref written, start);
+				written += separatorWritten;
+				if (space && !TryWrite((byte)' ', destination, ref written))
+					return Reset(ref written, start);
+			}
+
+			if (!components[i].TryFormat(destination[written..], out int componentWritten, format, provider))
+				return Reset(ref written, start);
+			written += componentWritten;
+		}
+
+		if (!TryWrite((byte)'>', destination, ref written))
+			return Reset(ref written, start);
+
+		return true;
+	}
+
+	private static bool TryWrite(byte value, Span<byte> destination, ref int written)
+	{
+		if (written >= destination.Length)
+			return false;
+
+		destination[written++] = value;
+		return true;
+	}
+#endif
+}
@@ -0,0 +1,90 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math.LinearAlgebra;
+
+/// <summary>
+/// Cholesky decomposition of a symmetric positive definite matrix, <c>A = L * L^T</c>.
+/// Only the lower triangle of the source matrix is read.
+/// </summary>
+public sealed class CholeskyDecomposition<T>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	private readonly Matrix<T> l;
+
+	public int Size => l.Rows;
+	public bool IsPositiveDefinite { get; }
+	/// <summary>Lower triangular factor.</summary>
+	public Matrix<T> L => l.Clone();
+
+	internal CholeskyDecomposition(Matrix<T> matrix)
+	{
+		if (!matrix.IsSquare)
+			throw new ArgumentException("Matrix must be square", nameof(matrix));
+
+		int n = matrix.Rows;
+
+		l = new(n, n);
+		IsPositiveDefinite = true;
+
+		for (int j = 0; j < n; j++)
+		{
+			Span<T> rowJ = l.GetRow(j);
+			T d = T.Zero;
+
+			for (int k = 0; k < j; k++)
+			{
+				ReadOnlySpan<T> rowK = l.GetRow(k);
+
+				T s = T.Zero;
+				for (int i = 0; i < k; i++)
+					s += rowK[i] * rowJ[i];
+
+				s = (matrix[j, k] - s) / rowK[k];
+				rowJ[k] = s;
+				d += s * s;
+			}
+
+			d = matrix[j, j] - d;
+
+			if (!(d > T.Zero))
+			{
+				IsPositiveDefinite = false;
+				d = T.Zero;
+			}
+
+			rowJ[j] = T.Sqrt(d);
+		}
+	}
+
+	/// <summary>Solves <c>A * x = b</c>.</summary>
+	/// <exception cref="InvalidOperationException">The matrix is not positive definite.</exception>
+	public T[] Solve(ReadOnlySpan<T> vector)
+	{
+		if (vector.Length != Size)
+			throw new ArgumentException("Vector length must be equal to matrix size", nameof(vector));
+		if (!IsPositiveDefinite)
+			throw new InvalidOperationException("Matrix is not positive definite");
+
+		int n = Size;
+		T[] x = vector.ToArray();
+
+		// Solve L * y = b
+		for (int k = 0; k < n; k++)
+		{
+			for (int i = 0; i < k; i++)
+				x[k] -= x[i] * l[k, i];
+			x[k] /= l[k, k];
+		}
+
+		// Solve L^T * x = y
+		for (int k = n - 1; k >= 0; k--)
+		{
+			for (int i = k + 1; i < n; i++)
+				x[k] -= x[i] * l[i, k];
+			x[k] /= l[k, k];
+		}
+
+		return x;
+	}
+}
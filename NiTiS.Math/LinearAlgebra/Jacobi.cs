@@ -0,0 +1,176 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math.LinearAlgebra;
+
+/// <summary>
+/// Jacobi rotation kernels over row-major spans, shared by fixed size and dynamic matrices.
+/// </summary>
+internal static class Jacobi
+{
+	private const int MaxSweeps = 64;
+
+	/// <summary>
+	/// Cyclic Jacobi eigenvalue algorithm for a symmetric <paramref name="n" /> x <paramref name="n" /> matrix.
+	/// <paramref name="matrix" /> is destroyed, eigenvectors are written as columns of <paramref name="vectors" />,
+	/// both sorted by descending eigenvalue.
+	/// </summary>
+	public static void EigenSymmetric<T>(Span<T> matrix, Span<T> values, Span<T> vectors, int n)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T epsilon = T.BitIncrement(T.One) - T.One;
+
+		vectors.Clear();
+		T norm = T.Zero;
+		for (int i = 0; i < n; i++)
+		{
+			vectors[i * n + i] = T.One;
+			for (int j = 0; j < n; j++)
+				norm += matrix[i * n + j] * matrix[i * n + j];
+		}
+
+		for (int sweep = 0; sweep < MaxSweeps; sweep++)
+		{
+			T off = T.Zero;
+			for (int p = 0; p < n - 1; p++)
+			{
+				for (int q = p + 1; q < n; q++)
+					off += matrix[p * n + q] * matrix[p * n + q];
+			}
+
+			if (!(off > epsilon * epsilon * norm))
+				break;
+
+			for (int p = 0; p < n - 1; p++)
+			{
+				for (int q = p + 1; q < n; q++)
+				{
+					T apq = matrix[p * n + q];
+					if (apq == T.Zero)
+						continue;
+
+					T theta = (matrix[q * n + q] - matrix[p * n + p]) / (apq + apq);
+					T t = T.CopySign(T.One, theta) / (T.Abs(theta) + T.Sqrt(theta * theta + T.One));
+					T c = T.One / T.Sqrt(t * t + T.One);
+					T s = t * c;
+
+					// A = J^T * A * J
+					for (int k = 0; k < n; k++)
+						Rotate(ref matrix[k * n + p], ref matrix[k * n + q], c, s);
+					for (int k = 0; k < n; k++)
+						Rotate(ref matrix[p * n + k], ref matrix[q * n + k], c, s);
+					// V = V * J
+					for (int k = 0; k < n; k++)
+						Rotate(ref vectors[k * n + p], ref vectors[k * n + q], c, s);
+				}
+			}
+		}
+
+		for (int i = 0; i < n; i++)
+			values[i] = matrix[i * n + i];
+
+		SortDescending(values, vectors, n, Span<T>.Empty, 0, n);
+	}
+
+	/// <summary>
+	/// One-sided Jacobi singular value decomposition of a <paramref name="m" /> x <paramref name="n" /> matrix with <c>m &gt;= n</c>.
+	/// <paramref name="u" /> holds the source matrix and is replaced with the left singular vectors,
+	/// right singular vectors are written as columns of <paramref name="v" />, both sorted by descending singular value.
+	/// </summary>
+	public static void Svd<T>(Span<T> u, Span<T> values, Span<T> v, int m, int n)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T epsilon = T.BitIncrement(T.One) - T.One;
+
+		v.Clear();
+		for (int i = 0; i < n; i++)
+			v[i * n + i] = T.One;
+
+		for (int sweep = 0; sweep < MaxSweeps; sweep++)
+		{
+			bool rotated = false;
+
+			for (int p = 0; p < n - 1; p++)
+			{
+				for (int q = p + 1; q < n; q++)
+				{
+					T alpha = T.Zero, beta = T.Zero, gamma = T.Zero;
+					for (int i = 0; i < m; i++)
+					{
+						T up = u[i * n + p], uq = u[i * n + q];
+						alpha += up * up;
+						beta += uq * uq;
+						gamma += up * uq;
+					}
+
+					if (!(T.Abs(gamma) > epsilon * T.Sqrt(alpha * beta)))
+						continue;
+
+					rotated = true;
+
+					T zeta = (beta - alpha) / (gamma + gamma);
+					T t = T.CopySign(T.One, zeta) / (T.Abs(zeta) + T.Sqrt(zeta * zeta + T.One));
+					T c = T.One / T.Sqrt(t * t + T.One);
+					T s = t * c;
+
+					for (int i = 0; i < m; i++)
+						Rotate(ref u[i * n + p], ref u[i * n + q], c, s);
+					for (int i = 0; i < n; i++)
+						Rotate(ref v[i * n + p], ref v[i * n + q], c, s);
+				}
+			}
+
+			if (!rotated)
+				break;
+		}
+
+		for (int j = 0; j < n; j++)
+		{
+			T norm = T.Zero;
+			for (int i = 0; i < m; i++)
+				norm = T.Hypot(norm, u[i * n + j]);
+
+			values[j] = norm;
+
+			if (norm != T.Zero)
+			{
+				for (int i = 0; i < m; i++)
+					u[i * n + j] /= norm;
+			}
+		}
+
+		SortDescending(values, u, m, v, n, n);
+	}
+
+	private static void Rotate<T>(ref T p, ref T q, T c, T s)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T a = p, b = q;
+		p = c * a - s * b;
+		q = s * a + c * b;
+	}
+
+	/// <summary>Selection sort of <paramref name="values" />, swapping the matching columns of both matrices.</summary>
+	private static void SortDescending<T>(Span<T> values, Span<T> first, int firstRows, Span<T> second, int secondRows, int n)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		for (int i = 0; i < n - 1; i++)
+		{
+			int max = i;
+			for (int j = i + 1; j < n; j++)
+			{
+				if (values[j] > values[max])
+					max = j;
+			}
+
+			if (max == i)
+				continue;
+
+			(values[i], values[max]) = (values[max], values[i]);
+			for (int k = 0; k < firstRows; k++)
+				(first[k * n + i], first[k * n + max]) = (first[k * n + max], first[k * n + i]);
+			for (int k = 0; k < secondRows; k++)
+				(second[k * n + i], second[k * n + max]) = (second[k * n + max], second[k * n + i]);
+		}
+	}
+}
@@ -0,0 +1,207 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math.LinearAlgebra;
+
+/// <summary>
+/// LU decomposition with partial pivoting of a square matrix, <c>P * A = L * U</c>.
+/// </summary>
+public sealed class LUDecomposition<T>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	private readonly Matrix<T> lu;
+	private readonly int[] pivots;
+	private readonly int pivotSign;
+	private readonly T norm;
+
+	public int Size => lu.Rows;
+	/// <summary>Row permutation, row <c>i</c> of <c>P * A</c> is row <c>Pivots[i]</c> of <c>A</c>.</summary>
+	public ReadOnlySpan<int> Pivots => pivots;
+	/// <summary>Whether a pivot is negligible against the infinity norm of the decomposed matrix, so solving would be dominated by rounding.</summary>
+	public bool IsSingular
+	{
+		get
+		{
+			T epsilon = T.BitIncrement(T.One) - T.One;
+			T tolerance = T.CreateTruncating(Size) * norm * epsilon;
+
+			for (int i = 0; i < Size; i++)
+			{
+				if (!(T.Abs(lu[i, i]) > tolerance))
+					return true;
+			}
+			return false;
+		}
+	}
+	/// <summary>Unit lower triangular factor.</summary>
+	public Matrix<T> L
+	{
+		get
+		{
+			Matrix<T> result = new(Size, Size);
+			for (int i = 0; i < Size; i++)
+			{
+				for (int j = 0; j < i; j++)
+					result[i, j] = lu[i, j];
+				result[i, i] = T.One;
+			}
+			return result;
+		}
+	}
+	/// <summary>Upper triangular factor.</summary>
+	public Matrix<T> U
+	{
+		get
+		{
+			Matrix<T> result = new(Size, Size);
+			for (int i = 0; i < Size; i++)
+			{
+				for (int j = i; j < Size; j++)
+					result[i, j] = lu[i, j];
+			}
+			return result;
+		}
+	}
+	public T Determinant
+	{
+		get
+		{
+			T det = pivotSign > 0 ? T.One : T.NegativeOne;
+			for (int i = 0; i < Size; i++)
+				det *= lu[i, i];
+			return det;
+		}
+	}
+
+	internal LUDecomposition(Matrix<T> matrix)
+	{
+		if (!matrix.IsSquare)
+			throw new ArgumentException("Matrix must be square", nameof(matrix));
+
+		int n = matrix.Rows;
+
+		lu = matrix.Clone();
+		pivots = new int[n];
+		pivotSign = 1;
+
+		for (int i = 0; i < n; i++)
+		{
+			pivots[i] = i;
+
+			T sum = T.Zero;
+			foreach (T value in matrix.GetRow(i))
+				sum += T.Abs(value);
+			norm = T.Max(norm, sum);
+		}
+
+		for (int k = 0; k < n; k++)
+		{
+			int p = k;
+			for (int i = k + 1; i < n; i++)
+			{
+				if (T.Abs(lu[i, k]) > T.Abs(lu[p, k]))
+					p = i;
+			}
+
+			if (p != k)
+			{
+				Span<T> rowP = lu.GetRow(p);
+				Span<T> rowK = lu.GetRow(k);
+				for (int j = 0; j < n; j++)
+					(rowP[j], rowK[j]) = (rowK[j], rowP[j]);
+
+				(pivots[p], pivots[k]) = (pivots[k], pivots[p]);
+				pivotSign = -pivotSign;
+			}
+
+			T pivot = lu[k, k];
+			if (pivot == T.Zero)
+				continue;
+
+			ReadOnlySpan<T> pivotRow = lu.GetRow(k);
+			for (int i = k + 1; i < n; i++)
+			{
+				Span<T> row = lu.GetRow(i);
+				T factor = row[k] /= pivot;
+
+				for (int j = k + 1; j < n; j++)
+					row[j] -= factor * pivotRow[j];
+			}
+		}
+	}
+
+	/// <summary>Solves <c>A * x = b</c>.</summary>
+	/// <exception cref="InvalidOperationException">The matrix is singular.</exception>
+	public T[] Solve(ReadOnlySpan<T> vector)
+	{
+		if (vector.Length != Size)
+			throw new ArgumentException("Vector length must be equal to matrix size", nameof(vector));
+		if (IsSingular)
+			throw new InvalidOperationException("Matrix is singular");
+
+		int n = Size;
+		T[] x = new T[n];
+
+		for (int i = 0; i < n; i++)
+			x[i] = vector[pivots[i]];
+
+		for (int k = 0; k < n; k++)
+		{
+			for (int i = k + 1; i < n; i++)
+				x[i] -= x[k] * lu[i, k];
+		}
+		for (int k = n - 1; k >= 0; k--)
+		{
+			x[k] /= lu[k, k];
+			for (int i = 0; i < k; i++)
+				x[i] -= x[k] * lu[i, k];
+		}
+
+		return x;
+	}
+	/// <summary>Solves <c>A * X = B</c>.</summary>
+	/// <exception cref="InvalidOperationException">The matrix is singular.</exception>
+	public Matrix<T> Solve(Matrix<T> matrix)
+	{
+		if (matrix.Rows != Size)
+			throw new ArgumentException("Matrix row count must be equal to decomposed matrix size", nameof(matrix));
+		if (IsSingular)
+			throw new InvalidOperationException("Matrix is singular");
+
+		int n = Size;
+		int count = matrix.Columns;
+		Matrix<T> x = new(n, count);
+
+		for (int i = 0; i < n; i++)
+			matrix.GetRow(pivots[i]).CopyTo(x.GetRow(i));
+
+		for (int k = 0; k < n; k++)
+		{
+			ReadOnlySpan<T> rowK = x.GetRow(k);
+			for (int i = k + 1; i < n; i++)
+			{
+				Span<T> row = x.GetRow(i);
+				T factor = lu[i, k];
+				for (int j = 0; j < count; j++)
+					row[j] -= rowK[j] * factor;
+			}
+		}
+		for (int k = n - 1; k >= 0; k--)
+		{
+			Span<T> rowK = x.GetRow(k);
+			T pivot = lu[k, k];
+			for (int j = 0; j < count; j++)
+				rowK[j] /= pivot;
+
+			for (int i = 0; i < k; i++)
+			{
+				Span<T> row = x.GetRow(i);
+				T factor = lu[i, k];
+				for (int j = 0; j < count; j++)
+					row[j] -= rowK[j] * factor;
+			}
+		}
+
+		return x;
+	}
+}
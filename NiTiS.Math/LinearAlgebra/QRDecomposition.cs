@@ -0,0 +1,187 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math.LinearAlgebra;
+
+/// <summary>
+/// QR decomposition of a matrix with at least as many rows as columns, computed with Householder reflections.
+/// </summary>
+public sealed class QRDecomposition<T>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	// Householder vectors below and on the diagonal, R above it
+	private readonly Matrix<T> qr;
+	private readonly T[] rDiagonal;
+	private readonly T norm;
+
+	public int Rows => qr.Rows;
+	public int Columns => qr.Columns;
+	/// <summary>Whether no diagonal entry of <see cref="R" /> is negligible against the infinity norm of the decomposed matrix.</summary>
+	public bool IsFullRank
+	{
+		get
+		{
+			T epsilon = T.BitIncrement(T.One) - T.One;
+			T tolerance = T.CreateTruncating(System.Math.Max(Rows, Columns)) * norm * epsilon;
+
+			foreach (T value in rDiagonal)
+			{
+				if (!(T.Abs(value) > tolerance))
+					return false;
+			}
+			return true;
+		}
+	}
+	/// <summary>Orthogonal factor with <see cref="Rows" /> rows and <see cref="Columns" /> columns.</summary>
+	public Matrix<T> Q
+	{
+		get
+		{
+			int m = Rows, n = Columns;
+			Matrix<T> result = new(m, n);
+
+			for (int k = n - 1; k >= 0; k--)
+			{
+				result[k, k] = T.One;
+
+				for (int j = k; j < n; j++)
+				{
+					if (qr[k, k] == T.Zero)
+						continue;
+
+					T s = T.Zero;
+					for (int i = k; i < m; i++)
+						s += qr[i, k] * result[i, j];
+
+					s = -s / qr[k, k];
+					for (int i = k; i < m; i++)
+						result[i, j] += s * qr[i, k];
+				}
+			}
+
+			return result;
+		}
+	}
+	/// <summary>Upper triangular factor with <see cref="Columns" /> rows and columns.</summary>
+	public Matrix<T> R
+	{
+		get
+		{
+			int n = Columns;
+			Matrix<T> result = new(n, n);
+
+			for (int i = 0; i < n; i++)
+			{
+				result[i, i] = rDiagonal[i];
+				for (int j = i + 1; j < n; j++)
+					result[i, j] = qr[i, j];
+			}
+
+			return result;
+		}
+	}
+
+	internal QRDecomposition(Matrix<T> matrix)
+	{
+		if (matrix.Rows < matrix.Columns)
+			throw new ArgumentException("Matrix must have at least as many rows as columns", nameof(matrix));
+
+		int m = matrix.Rows, n = matrix.Columns;
+
+		qr = matrix.Clone();
+		rDiagonal = new T[n];
+
+		for (int i = 0; i < m; i++)
+		{
+			T sum = T.Zero;
+			foreach (T value in matrix.GetRow(i))
+				sum += T.Abs(value);
+			this.norm = T.Max(this.norm, sum);
+		}
+
+		for (int k = 0; k < n; k++)
+		{
+			T norm = T.Zero;
+			for (int i = k; i < m; i++)
+				norm = T.Hypot(norm, qr[i, k]);
+
+			if (norm != T.Zero)
+			{
+				if (qr[k, k] < T.Zero)
+					norm = -norm;
+
+				for (int i = k; i < m; i++)
+					qr[i, k] /= norm;
+
+				qr[k, k] += T.One;
+
+				for (int j = k + 1; j < n; j++)
+				{
+					T s = T.Zero;
+					for (int i = k; i < m; i++)
+						s += qr[i, k] * qr[i, j];
+
+					s = -s / qr[k, k];
+					for (int i = k; i < m; i++)
+						qr[i, j] += s * qr[i, k];
+				}
+			}
+
+			rDiagonal[k] = -norm;
+		}
+	}
+
+	/// <summary>Finds the least squares solution of <c>A * x = b</c>.</summary>
+	/// <exception cref="InvalidOperationException">The matrix is rank deficient.</exception>
+	public T[] Solve(ReadOnlySpan<T> vector)
+	{
+		if (vector.Length != Rows)
+			throw new ArgumentException("Vector length must be equal to matrix row count", nameof(vector));
+
+		Matrix<T> x = Solve(new Matrix<T>(vector.Length, 1, vector));
+		return x.AsSpan().ToArray();
+	}
+	/// <summary>Finds the least squares solution of <c>A * X = B</c>.</summary>
+	/// <exception cref="InvalidOperationException">The matrix is rank deficient.</exception>
+	public Matrix<T> Solve(Matrix<T> matrix)
+	{
+		if (matrix.Rows != Rows)
+			throw new ArgumentException("Matrix row count must be equal to decomposed matrix row count", nameof(matrix));
+		if (!IsFullRank)
+			throw new InvalidOperationException("Matrix is rank deficient");
+
+		int m = Rows, n = Columns;
+		int count = matrix.Columns;
+		Matrix<T> x = matrix.Clone();
+
+		// Compute Q^T * B
+		for (int k = 0; k < n; k++)
+		{
+			for (int j = 0; j < count; j++)
+			{
+				T s = T.Zero;
+				for (int i = k; i < m; i++)
+					s += qr[i, k] * x[i, j];
+
+				s = -s / qr[k, k];
+				for (int i = k; i < m; i++)
+					x[i, j] += s * qr[i, k];
+			}
+		}
+
+		// Solve R * X = Q^T * B
+		for (int k = n - 1; k >= 0; k--)
+		{
+			for (int j = 0; j < count; j++)
+				x[k, j] /= rDiagonal[k];
+
+			for (int i = 0; i < k; i++)
+			{
+				for (int j = 0; j < count; j++)
+					x[i, j] -= x[k, j] * qr[i, k];
+			}
+		}
+
+		return x.Slice(0, 0, n, count);
+	}
+}
@@ -0,0 +1,76 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math.LinearAlgebra;
+
+/// <summary>
+/// Thin singular value decomposition, <c>A = U * S * V^T</c>, computed with one-sided Jacobi rotations.
+/// </summary>
+public sealed class SingularValueDecomposition<T>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	private readonly Matrix<T> u;
+	private readonly Matrix<T> v;
+	private readonly T[] s;
+
+	public int Rows => u.Rows;
+	public int Columns => v.Rows;
+	/// <summary>Singular values in descending order, there are <c>min(Rows, Columns)</c> of them.</summary>
+	public ReadOnlySpan<T> SingularValues => s;
+	/// <summary>Left singular vectors as columns.</summary>
+	public Matrix<T> U => u.Clone();
+	/// <summary>Right singular vectors as columns.</summary>
+	public Matrix<T> V => v.Clone();
+	/// <summary>Diagonal matrix of singular values.</summary>
+	public Matrix<T> S
+	{
+		get
+		{
+			Matrix<T> result = new(s.Length, s.Length);
+			for (int i = 0; i < s.Length; i++)
+				result[i, i] = s[i];
+			return result;
+		}
+	}
+	/// <summary>Two norm of the matrix, the largest singular value.</summary>
+	public T Norm2 => s.Length == 0 ? T.Zero : s[0];
+	/// <summary>Ratio of the largest to the smallest singular value.</summary>
+	public T ConditionNumber => s.Length == 0 ? T.Zero : s[0] / s[^1];
+	/// <summary>Number of singular values that are not negligible.</summary>
+	public int Rank
+	{
+		get
+		{
+			if (s.Length == 0)
+				return 0;
+
+			T epsilon = T.BitIncrement(T.One) - T.One;
+			T tolerance = T.CreateTruncating(System.Math.Max(Rows, Columns)) * s[0] * epsilon;
+
+			int rank = 0;
+			foreach (T value in s)
+			{
+				if (value > tolerance)
+					rank++;
+			}
+			return rank;
+		}
+	}
+
+	internal SingularValueDecomposition(Matrix<T> matrix)
+	{
+		bool transposed = matrix.Rows < matrix.Columns;
+		Matrix<T> a = transposed ? Matrix.Transpose(matrix) : matrix.Clone();
+
+		int m = a.Rows, n = a.Columns;
+
+		Matrix<T> left = a;
+		Matrix<T> right = new(n, n);
+		s = new T[n];
+
+		Jacobi.Svd(left.AsSpan(), s, right.AsSpan(), m, n);
+
+		// A^T = U' * S * V'^T gives A = V' * S * U'^T
+		(u, v) = transposed ? (right, left) : (left, right);
+	}
+}
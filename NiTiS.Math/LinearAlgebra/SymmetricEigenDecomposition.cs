@@ -0,0 +1,282 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math.LinearAlgebra;
+
+/// <summary>
+/// Eigen decomposition of a symmetric matrix, <c>A = V * D * V^T</c>, computed with Householder tridiagonalization and the implicit QL algorithm.
+/// Only the lower triangle of the source matrix is read.
+/// </summary>
+public sealed class SymmetricEigenDecomposition<T>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	private readonly Matrix<T> v;
+	private readonly T[] d;
+
+	public int Size => v.Rows;
+	/// <summary>Eigenvalues in descending order.</summary>
+	public ReadOnlySpan<T> Eigenvalues => d;
+	/// <summary>Orthogonal matrix whose columns are the eigenvectors, column <c>i</c> belongs to <c>Eigenvalues[i]</c>.</summary>
+	public Matrix<T> Eigenvectors => v.Clone();
+	/// <summary>Diagonal matrix of eigenvalues.</summary>
+	public Matrix<T> D
+	{
+		get
+		{
+			Matrix<T> result = new(Size, Size);
+			for (int i = 0; i < Size; i++)
+				result[i, i] = d[i];
+			return result;
+		}
+	}
+
+	internal SymmetricEigenDecomposition(Matrix<T> matrix)
+	{
+		if (!matrix.IsSquare)
+			throw new ArgumentException("Matrix must be square", nameof(matrix));
+
+		int n = matrix.Rows;
+
+		v = new(n, n);
+		d = new T[n];
+		T[] e = new T[n];
+
+		for (int i = 0; i < n; i++)
+		{
+			for (int j = 0; j <= i; j++)
+				v[i, j] = v[j, i] = matrix[i, j];
+		}
+
+		if (n == 0)
+			return;
+
+		Tridiagonalize(e);
+		DiagonalizeQL(e);
+		Sort();
+	}
+
+	// Householder reduction to tridiagonal form, d and e receive the diagonal and subdiagonal
+	private void Tridiagonalize(T[] e)
+	{
+		int n = Size;
+
+		for (int j = 0; j < n; j++)
+			d[j] = v[n - 1, j];
+
+		for (int i = n - 1; i > 0; i--)
+		{
+			T scale = T.Zero;
+			T h = T.Zero;
+			for (int k = 0; k < i; k++)
+				scale += T.Abs(d[k]);
+
+			if (scale == T.Zero)
+			{
+				e[i] = d[i - 1];
+				for (int j = 0; j < i; j++)
+				{
+					d[j] = v[i - 1, j];
+					v[i, j] = T.Zero;
+					v[j, i] = T.Zero;
+				}
+			}
+			else
+			{
+				for (int k = 0; k < i; k++)
+				{
+					d[k] /= scale;
+					h += d[k] * d[k];
+				}
+
+				T f = d[i - 1];
+				T g = T.Sqrt(h);
+				if (f > T.Zero)
+					g = -g;
+
+				e[i] = scale * g;
+				h -= f * g;
+				d[i - 1] = f - g;
+
+				for (int j = 0; j < i; j++)
+					e[j] = T.Zero;
+
+				for (int j = 0; j < i; j++)
+				{
+					f = d[j];
+					v[j, i] = f;
+					g = e[j] + v[j, j] * f;
+
+					for (int k = j + 1; k < i; k++)
+					{
+						g += v[k, j] * d[k];
+						e[k] += v[k, j] * f;
+					}
+
+					e[j] = g;
+				}
+
+				f = T.Zero;
+				for (int j = 0; j < i; j++)
+				{
+					e[j] /= h;
+					f += e[j] * d[j];
+				}
+
+				T hh = f / (h + h);
+				for (int j = 0; j < i; j++)
+					e[j] -= hh * d[j];
+
+				for (int j = 0; j < i; j++)
+				{
+					f = d[j];
+					g = e[j];
+
+					for (int k = j; k < i; k++)
+						v[k, j] -= f * e[k] + g * d[k];
+
+					d[j] = v[i - 1, j];
+					v[i, j] = T.Zero;
+				}
+			}
+
+			d[i] = h;
+		}
+
+		// Accumulate transformations
+		for (int i = 0; i < n - 1; i++)
+		{
+			v[n - 1, i] = v[i, i];
+			v[i, i] = T.One;
+
+			T h = d[i + 1];
+			if (h != T.Zero)
+			{
+				for (int k = 0; k <= i; k++)
+					d[k] = v[k, i + 1] / h;
+
+				for (int j = 0; j <= i; j++)
+				{
+					T g = T.Zero;
+					for (int k = 0; k <= i; k++)
+						g += v[k, i + 1] * v[k, j];
+					for (int k = 0; k <= i; k++)
+						v[k, j] -= g * d[k];
+				}
+			}
+
+			for (int k = 0; k <= i; k++)
+				v[k, i + 1] = T.Zero;
+		}
+
+		for (int j = 0; j < n; j++)
+		{
+			d[j] = v[n - 1, j];
+			v[n - 1, j] = T.Zero;
+		}
+
+		v[n - 1, n - 1] = T.One;
+		e[0] = T.Zero;
+	}
+
+	// Implicit QL iterations on the tridiagonal matrix
+	private void DiagonalizeQL(T[] e)
+	{
+		int n = Size;
+		T epsilon = T.BitIncrement(T.One) - T.One;
+		T two = T.One + T.One;
+
+		for (int i = 1; i < n; i++)
+			e[i - 1] = e[i];
+		e[n - 1] = T.Zero;
+
+		T f = T.Zero;
+		T tst1 = T.Zero;
+
+		for (int l = 0; l < n; l++)
+		{
+			tst1 = T.Max(tst1, T.Abs(d[l]) + T.Abs(e[l]));
+
+			int m = l;
+			while (m < n - 1 && T.Abs(e[m]) > epsilon * tst1)
+				m++;
+
+			if (m > l)
+			{
+				do
+				{
+					T g = d[l];
+					T p = (d[l + 1] - g) / (two * e[l]);
+					T r = T.Hypot(p, T.One);
+					if (p < T.Zero)
+						r = -r;
+
+					d[l] = e[l] / (p + r);
+					d[l + 1] = e[l] * (p + r);
+
+					T dl1 = d[l + 1];
+					T h = g - d[l];
+					for (int i = l + 2; i < n; i++)
+						d[i] -= h;
+					f += h;
+
+					p = d[m];
+					T c = T.One, c2 = T.One, c3 = T.One;
+					T el1 = e[l + 1];
+					T s = T.Zero, s2 = T.Zero;
+
+					for (int i = m - 1; i >= l; i--)
+					{
+						c3 = c2;
+						c2 = c;
+						s2 = s;
+						g = c * e[i];
+						h = c * p;
+						r = T.Hypot(p, e[i]);
+						e[i + 1] = s * r;
+						s = e[i] / r;
+						c = p / r;
+						p = c * d[i] - s * g;
+						d[i + 1] = h + s * (c * g + s * d[i]);
+
+						for (int k = 0; k < n; k++)
+						{
+							h = v[k, i + 1];
+							v[k, i + 1] = s * v[k, i] + c * h;
+							v[k, i] = c * v[k, i] - s * h;
+						}
+					}
+
+					p = -s * s2 * c3 * el1 * e[l] / dl1;
+					e[l] = s * p;
+					d[l] = c * p;
+				}
+				while (T.Abs(e[l]) > epsilon * tst1);
+			}
+
+			d[l] += f;
+			e[l] = T.Zero;
+		}
+	}
+
+	private void Sort()
+	{
+		int n = Size;
+
+		for (int i = 0; i < n - 1; i++)
+		{
+			int max = i;
+			for (int j = i + 1; j < n; j++)
+			{
+				if (d[j] > d[max])
+					max = j;
+			}
+
+			if (max == i)
+				continue;
+
+			(d[i], d[max]) = (d[max], d[i]);
+			for (int k = 0; k < n; k++)
+				(v[k, i], v[k, max]) = (v[k, max], v[k, i]);
+		}
+	}
+}
@@ -0,0 +1,109 @@
+﻿using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class Mat2
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> Add<T>(Mat2<T> left, Mat2<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left + right;
+
+	public static Mat2<T> CreateRotation<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T c = T.Cos(angle.Radians), s = T.Sin(angle.Radians);
+
+		return new(
+			c, s,
+			-s, c
+			);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> CreateScale<T>(T scale)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(scale, scale);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> CreateScale<T>(Vector2D<T> scales)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(scales.X, scales.Y);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> CreateScale<T>(T xScale, T yScale)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			xScale, T.Zero,
+			T.Zero, yScale
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Determinant<T>(Mat2<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> matrix.M11 * matrix.M22 - matrix.M12 * matrix.M21;
+
+	/// <summary>Attempts to invert the given matrix.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat2<T> matrix, out Mat2<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+		=> Invert(matrix, T.Zero, out result);
+	/// <summary>Attempts to invert the given matrix, treating it as singular when the absolute value of its determinant is not greater than <paramref name="epsilon" />.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="epsilon">The largest absolute determinant still considered singular, it is absolute rather than scaled by the matrix norm, so choose it for the expected magnitude of the determinant.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat2<T> matrix, T epsilon, out Mat2<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+	{
+		T det = Determinant(matrix);
+
+		if (!(T.Abs(det) > epsilon))
+		{
+			result = default;
+			return false;
+		}
+
+		T invDet = T.One / det;
+
+		result = new(
+			matrix.M22 * invDet, -matrix.M12 * invDet,
+			-matrix.M21 * invDet, matrix.M11 * invDet
+			);
+		return true;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> Multiply<T>(Mat2<T> left, Mat2<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> Multiply<T>(Mat2<T> left, T right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> Negate<T>(Mat2<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> -operand;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> Subtract<T>(Mat2<T> left, Mat2<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left - right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Trace<T>(Mat2<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> matrix.M11 + matrix.M22;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> Transpose<T>(Mat2<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			matrix.M11, matrix.M21,
+			matrix.M12, matrix.M22
+			);
+}
@@ -0,0 +1,128 @@
+﻿using NiTiS.Core.Operators;
+using System;
+using System.Diagnostics.CodeAnalysis;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public readonly struct Mat2<T> :
+	// Matrix op Matrix
+	IAdditionOperators<Mat2<T>, Mat2<T>, Mat2<T>>,
+	ISubtractionOperators<Mat2<T>, Mat2<T>, Mat2<T>>,
+	IMultiplyOperators<Mat2<T>, Mat2<T>, Mat2<T>>,
+	IEqualityOperators<Mat2<T>, Mat2<T>, bool>,
+	// Matrix op T
+	IMultiplyOperators<Mat2<T>, T, Mat2<T>>,
+	// Unary op
+	IUnaryNegationOperators<Mat2<T>, Mat2<T>>,
+	IUnaryPlusOperators<Mat2<T>, Mat2<T>>,
+	IEquatable<Mat2<T>>,
+	// Cast op
+	IExplicitCastOperators<Mat2<T>, Mat4<T>>
+	where T : unmanaged, INumberBase<T>
+{
+	#region Matrix
+	public readonly T M11;
+	public readonly T M12;
+
+	public readonly T M21;
+	public readonly T M22;
+	#endregion
+	public static Mat2<T> Identity => new(
+		T.One, T.Zero,
+		T.Zero, T.One
+		);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public Mat2(
+		T m11, T m12,
+		T m21, T m22
+		)
+	{
+		M11 = m11;
+		M12 = m12;
+		M21 = m21;
+		M22 = m22;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> operator +(Mat2<T> left, Mat2<T> right)
+		=> new(
+			left.M11 + right.M11, left.M12 + right.M12,
+			left.M21 + right.M21, left.M22 + right.M22
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> operator -(Mat2<T> left, Mat2<T> right)
+		=> new(
+			left.M11 - right.M11, left.M12 - right.M12,
+			left.M21 - right.M21, left.M22 - right.M22
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> operator *(Mat2<T> left, Mat2<T> right)
+		=> new(
+			left.M11 * right.M11 + left.M12 * right.M21,
+			left.M11 * right.M12 + left.M12 * right.M22,
+
+			left.M21 * right.M11 + left.M22 * right.M21,
+			left.M21 * right.M12 + left.M22 * right.M22
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(Mat2<T> left, Mat2<T> right)
+		=> left.M11 == right.M11 && left.M12 == right.M12
+		&& left.M21 == right.M21 && left.M22 == right.M22;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(Mat2<T> left, Mat2<T> right)
+		=> !(left == right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> operator *(Mat2<T> left, T right)
+		=> new(
+			left.M11 * right, left.M12 * right,
+			left.M21 * right, left.M22 * right
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> operator *(T left, Mat2<T> right)
+		=> right * left;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> operator -(Mat2<T> operand)
+		=> new(
+			-operand.M11, -operand.M12,
+			-operand.M21, -operand.M22
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat2<T> operator +(Mat2<T> operand)
+		=> operand;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat4<T>(Mat2<T> operand)
+		=> new(
+			operand.M11, operand.M12, T.Zero, T.Zero,
+			operand.M21, operand.M22, T.Zero, T.Zero,
+			T.Zero, T.Zero, T.One, T.Zero,
+			T.Zero, T.Zero, T.Zero, T.One
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat2<T>(Mat4<T> operand)
+		=> new(
+			operand.M11, operand.M12,
+			operand.M21, operand.M22
+			);
+
+	/// <summary>Indicates whether the current matrix is the identity matrix.</summary>
+	/// <value><see langword="true" /> if the current matrix is the identity matrix; otherwise, <see langword="false" />.</value>
+	public readonly bool IsIdentity
+		=> M11 == T.One && M22 == T.One
+		&& M12 == T.Zero && M21 == T.Zero;
+
+	public override readonly int GetHashCode()
+		=> HashCode.Combine(M11, M12, M21, M22);
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Mat2<T> mat
+		? mat == this : false;
+	public readonly bool Equals(Mat2<T> other)
+		=> this == other;
+}
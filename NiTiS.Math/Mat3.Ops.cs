@@ -0,0 +1,259 @@
+﻿using NiTiS.Math.LinearAlgebra;
+using System;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class Mat3
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> Add<T>(Mat3<T> left, Mat3<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left + right;
+
+	/// <summary>Computes the covariance matrix of the given points, normalized by the point count.</summary>
+	/// <remarks>Eigenvectors of the covariance matrix are the principal axes of the point cloud, see <see cref="DecomposeSymmetricEigen{T}(Mat3{T}, out Vector3D{T}, out Mat3{T})" />.</remarks>
+	public static Mat3<T> CreateCovariance<T>(ReadOnlySpan<Vector3D<T>> points)
+		where T : unmanaged, INumberBase<T>
+		=> CreateCovariance(points, out _);
+	/// <summary>Computes the covariance matrix of the given points, normalized by the point count.</summary>
+	/// <param name="mean">Centroid of the points.</param>
+	public static Mat3<T> CreateCovariance<T>(ReadOnlySpan<Vector3D<T>> points, out Vector3D<T> mean)
+		where T : unmanaged, INumberBase<T>
+	{
+		if (points.IsEmpty)
+		{
+			mean = Vector3D<T>.Zero;
+			return default;
+		}
+
+		T count = T.CreateTruncating(points.Length);
+
+		Vector3D<T> sum = Vector3D<T>.Zero;
+		foreach (Vector3D<T> point in points)
+			sum += point;
+		mean = sum / count;
+
+		T xx = T.Zero, xy = T.Zero, xz = T.Zero;
+		T yy = T.Zero, yz = T.Zero, zz = T.Zero;
+		foreach (Vector3D<T> point in points)
+		{
+			Vector3D<T> d = point - mean;
+			xx += d.X * d.X;
+			xy += d.X * d.Y;
+			xz += d.X * d.Z;
+			yy += d.Y * d.Y;
+			yz += d.Y * d.Z;
+			zz += d.Z * d.Z;
+		}
+
+		xx /= count;
+		xy /= count;
+		xz /= count;
+		yy /= count;
+		yz /= count;
+		zz /= count;
+
+		return new(
+			xx, xy, xz,
+			xy, yy, yz,
+			xz, yz, zz
+			);
+	}
+
+	/// <summary>Decomposes the matrix into a rotation and a symmetric stretch, <c>matrix = rotation * stretch</c>.</summary>
+	/// <param name="rotation">Orthonormal matrix with determinant of one.</param>
+	/// <param name="stretch">Symmetric matrix, positive semi-definite unless <paramref name="matrix" /> contains a reflection.</param>
+	public static void DecomposePolar<T>(Mat3<T> matrix, out Mat3<T> rotation, out Mat3<T> stretch)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		DecomposeSvd(matrix, out Mat3<T> u, out Vector3D<T> s, out Mat3<T> v);
+
+		Mat3<T> vt = Transpose(v);
+
+		// Move a reflection into the stretch through the smallest singular value
+		if (Determinant(u) * Determinant(v) < T.Zero)
+		{
+			u = new(
+				u.M11, u.M12, -u.M13,
+				u.M21, u.M22, -u.M23,
+				u.M31, u.M32, -u.M33
+				);
+			s = new(s.X, s.Y, -s.Z);
+		}
+
+		rotation = u * vt;
+		stretch = v * new Mat3<T>(
+			s.X, T.Zero, T.Zero,
+			T.Zero, s.Y, T.Zero,
+			T.Zero, T.Zero, s.Z
+			) * vt;
+	}
+
+	/// <summary>Computes the singular value decomposition, <c>matrix = u * diag(singularValues) * transpose(v)</c>.</summary>
+	/// <param name="u">Orthonormal matrix whose columns are the left singular vectors.</param>
+	/// <param name="singularValues">Singular values in descending order.</param>
+	/// <param name="v">Orthonormal matrix whose columns are the right singular vectors.</param>
+	public static void DecomposeSvd<T>(Mat3<T> matrix, out Mat3<T> u, out Vector3D<T> singularValues, out Mat3<T> v)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Span<T> a = stackalloc T[9];
+		Span<T> values = stackalloc T[3];
+		Span<T> vectors = stackalloc T[9];
+
+		Store(matrix, a);
+		Jacobi.Svd(a, values, vectors, 3, 3);
+
+		u = Load(a);
+		singularValues = new(values[0], values[1], values[2]);
+		v = Load(vectors);
+
+		// Columns belonging to vanishing singular values carry no direction, complete the basis instead
+		T tolerance = values[0] * (T.BitIncrement(T.One) - T.One) * T.CreateTruncating(3);
+
+		if (!(values[1] > tolerance))
+		{
+			Vector3D<T> column = values[0] == T.Zero
+				? Vector3D<T>.UnitX
+				: new(u.M11, u.M21, u.M31);
+
+			Vector3D<T> other = Vector3D.Normalize(Vector3D.Cross(column, T.Abs(column.X) < T.CreateTruncating(0.9) ? Vector3D<T>.UnitX : Vector3D<T>.UnitY));
+			Vector3D<T> last = Vector3D.Cross(column, other);
+			u = new(
+				column.X, other.X, last.X,
+				column.Y, other.Y, last.Y,
+				column.Z, other.Z, last.Z
+				);
+		}
+		else if (!(values[2] > tolerance))
+		{
+			Vector3D<T> last = Vector3D.Cross(new Vector3D<T>(u.M11, u.M21, u.M31), new Vector3D<T>(u.M12, u.M22, u.M32));
+			u = new(
+				u.M11, u.M12, last.X,
+				u.M21, u.M22, last.Y,
+				u.M31, u.M32, last.Z
+				);
+		}
+	}
+
+	/// <summary>Computes eigenvalues and eigenvectors of the given symmetric matrix with the Jacobi eigenvalue algorithm.</summary>
+	/// <param name="eigenvalues">Eigenvalues in descending order.</param>
+	/// <param name="eigenvectors">Orthonormal matrix whose columns are the eigenvectors, column <c>i</c> belongs to the <c>i</c>-th eigenvalue.</param>
+	public static void DecomposeSymmetricEigen<T>(Mat3<T> matrix, out Vector3D<T> eigenvalues, out Mat3<T> eigenvectors)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Span<T> a = stackalloc T[9];
+		Span<T> values = stackalloc T[3];
+		Span<T> vectors = stackalloc T[9];
+
+		Store(matrix, a);
+		Jacobi.EigenSymmetric(a, values, vectors, 3);
+
+		eigenvalues = new(values[0], values[1], values[2]);
+		eigenvectors = Load(vectors);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Determinant<T>(Mat3<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> matrix.M11 * (matrix.M22 * matrix.M33 - matrix.M23 * matrix.M32)
+		+ matrix.M12 * (matrix.M23 * matrix.M31 - matrix.M21 * matrix.M33)
+		+ matrix.M13 * (matrix.M21 * matrix.M32 - matrix.M22 * matrix.M31);
+
+	/// <summary>Attempts to invert the given matrix.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat3<T> matrix, out Mat3<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+		=> Invert(matrix, T.Zero, out result);
+	/// <summary>Attempts to invert the given matrix, treating it as singular when the absolute value of its determinant is not greater than <paramref name="epsilon" />.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="epsilon">The largest absolute determinant still considered singular, it is absolute rather than scaled by the matrix norm, so choose it for the expected magnitude of the determinant.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat3<T> matrix, T epsilon, out Mat3<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+	{
+		T a = matrix.M11, b = matrix.M12, c = matrix.M13;
+		T d = matrix.M21, e = matrix.M22, f = matrix.M23;
+		T g = matrix.M31, h = matrix.M32, i = matrix.M33;
+
+		T ei_fh = e * i - f * h;
+		T fg_di = f * g - d * i;
+		T dh_eg = d * h - e * g;
+
+		T det = a * ei_fh + b * fg_di + c * dh_eg;
+
+		if (!(T.Abs(det) > epsilon))
+		{
+			result = default;
+			return false;
+		}
+
+		T invDet = T.One / det;
+
+		result = new(
+			ei_fh * invDet, (c * h - b * i) * invDet, (b * f - c * e) * invDet,
+			fg_di * invDet, (a * i - c * g) * invDet, (c * d - a * f) * invDet,
+			dh_eg * invDet, (b * g - a * h) * invDet, (a * e - b * d) * invDet
+			);
+		return true;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> Multiply<T>(Mat3<T> left, Mat3<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> Multiply<T>(Mat3<T> left, T right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> Negate<T>(Mat3<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> -operand;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> Subtract<T>(Mat3<T> left, Mat3<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left - right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Trace<T>(Mat3<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> matrix.M11 + matrix.M22 + matrix.M33;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> Transpose<T>(Mat3<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			matrix.M11, matrix.M21, matrix.M31,
+			matrix.M12, matrix.M22, matrix.M32,
+			matrix.M13, matrix.M23, matrix.M33
+			);
+
+	private static void Store<T>(Mat3<T> matrix, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		destination[0] = matrix.M11;
+		destination[1] = matrix.M12;
+		destination[2] = matrix.M13;
+		destination[3] = matrix.M21;
+		destination[4] = matrix.M22;
+		destination[5] = matrix.M23;
+		destination[6] = matrix.M31;
+		destination[7] = matrix.M32;
+		destination[8] = matrix.M33;
+	}
+	private static Mat3<T> Load<T>(ReadOnlySpan<T> source)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			source[0], source[1], source[2],
+			source[3], source[4], source[5],
+			source[6], source[7], source[8]
+			);
+}
@@ -0,0 +1,168 @@
+﻿using NiTiS.Core.Operators;
+using System;
+using System.Diagnostics.CodeAnalysis;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public readonly struct Mat3<T> :
+	// Matrix op Matrix
+	IAdditionOperators<Mat3<T>, Mat3<T>, Mat3<T>>,
+	ISubtractionOperators<Mat3<T>, Mat3<T>, Mat3<T>>,
+	IMultiplyOperators<Mat3<T>, Mat3<T>, Mat3<T>>,
+	IEqualityOperators<Mat3<T>, Mat3<T>, bool>,
+	// Matrix op T
+	IMultiplyOperators<Mat3<T>, T, Mat3<T>>,
+	// Unary op
+	IUnaryNegationOperators<Mat3<T>, Mat3<T>>,
+	IUnaryPlusOperators<Mat3<T>, Mat3<T>>,
+	IEquatable<Mat3<T>>,
+	// Cast op
+	IExplicitCastOperators<Mat3<T>, Mat4<T>>,
+	IExplicitCastOperators<Mat3<T>, Mat3x2<T>>
+	where T : unmanaged, INumberBase<T>
+{
+	#region Matrix
+	public readonly T M11;
+	public readonly T M12;
+	public readonly T M13;
+
+	public readonly T M21;
+	public readonly T M22;
+	public readonly T M23;
+
+	public readonly T M31;
+	public readonly T M32;
+	public readonly T M33;
+	#endregion
+	public static Mat3<T> Identity => new(
+		T.One, T.Zero, T.Zero,
+		T.Zero, T.One, T.Zero,
+		T.Zero, T.Zero, T.One
+		);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public Mat3(
+		T m11, T m12, T m13,
+		T m21, T m22, T m23,
+		T m31, T m32, T m33
+		)
+	{
+		M11 = m11;
+		M12 = m12;
+		M13 = m13;
+		M21 = m21;
+		M22 = m22;
+		M23 = m23;
+		M31 = m31;
+		M32 = m32;
+		M33 = m33;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> operator +(Mat3<T> left, Mat3<T> right)
+		=> new(
+			left.M11 + right.M11, left.M12 + right.M12, left.M13 + right.M13,
+			left.M21 + right.M21, left.M22 + right.M22, left.M23 + right.M23,
+			left.M31 + right.M31, left.M32 + right.M32, left.M33 + right.M33
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> operator -(Mat3<T> left, Mat3<T> right)
+		=> new(
+			left.M11 - right.M11, left.M12 - right.M12, left.M13 - right.M13,
+			left.M21 - right.M21, left.M22 - right.M22, left.M23 - right.M23,
+			left.M31 - right.M31, left.M32 - right.M32, left.M33 - right.M33
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> operator *(Mat3<T> left, Mat3<T> right)
+		=> new(
+			left.M11 * right.M11 + left.M12 * right.M21 + left.M13 * right.M31,
+			left.M11 * right.M12 + left.M12 * right.M22 + left.M13 * right.M32,
+			left.M11 * right.M13 + left.M12 * right.M23 + left.M13 * right.M33,
+
+			left.M21 * right.M11 + left.M22 * right.M21 + left.M23 * right.M31,
+			left.M21 * right.M12 + left.M22 * right.M22 + left.M23 * right.M32,
+			left.M21 * right.M13 + left.M22 * right.M23 + left.M23 * right.M33,
+
+			left.M31 * right.M11 + left.M32 * right.M21 + left.M33 * right.M31,
+			left.M31 * right.M12 + left.M32 * right.M22 + left.M33 * right.M32,
+			left.M31 * right.M13 + left.M32 * right.M23 + left.M33 * right.M33
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(Mat3<T> left, Mat3<T> right)
+		=> left.M11 == right.M11 && left.M12 == right.M12 && left.M13 == right.M13
+		&& left.M21 == right.M21 && left.M22 == right.M22 && left.M23 == right.M23
+		&& left.M31 == right.M31 && left.M32 == right.M32 && left.M33 == right.M33;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(Mat3<T> left, Mat3<T> right)
+		=> !(left == right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> operator *(Mat3<T> left, T right)
+		=> new(
+			left.M11 * right, left.M12 * right, left.M13 * right,
+			left.M21 * right, left.M22 * right, left.M23 * right,
+			left.M31 * right, left.M32 * right, left.M33 * right
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> operator *(T left, Mat3<T> right)
+		=> right * left;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> operator -(Mat3<T> operand)
+		=> new(
+			-operand.M11, -operand.M12, -operand.M13,
+			-operand.M21, -operand.M22, -operand.M23,
+			-operand.M31, -operand.M32, -operand.M33
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3<T> operator +(Mat3<T> operand)
+		=> operand;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat4<T>(Mat3<T> operand)
+		=> new(
+			operand.M11, operand.M12, operand.M13, T.Zero,
+			operand.M21, operand.M22, operand.M23, T.Zero,
+			operand.M31, operand.M32, operand.M33, T.Zero,
+			T.Zero, T.Zero, T.Zero, T.One
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat3<T>(Mat4<T> operand)
+		=> new(
+			operand.M11, operand.M12, operand.M13,
+			operand.M21, operand.M22, operand.M23,
+			operand.M31, operand.M32, operand.M33
+			);
+	/// <summary>Drops the last column of a 2D homogeneous matrix.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat3x2<T>(Mat3<T> operand)
+		=> new(
+			operand.M11, operand.M12,
+			operand.M21, operand.M22,
+			operand.M31, operand.M32
+			);
+
+	/// <summary>Indicates whether the current matrix is the identity matrix.</summary>
+	/// <value><see langword="true" /> if the current matrix is the identity matrix; otherwise, <see langword="false" />.</value>
+	public readonly bool IsIdentity
+		=> M11 == T.One && M22 == T.One && M33 == T.One
+		&& M12 == T.Zero && M13 == T.Zero
+		&& M21 == T.Zero && M23 == T.Zero
+		&& M31 == T.Zero && M32 == T.Zero;
+
+	public override readonly int GetHashCode()
+		=> HashCode.Combine(
+			HashCode.Combine(M11, M12, M13),
+			HashCode.Combine(M21, M22, M23),
+			HashCode.Combine(M31, M32, M33)
+			);
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Mat3<T> mat
+		? mat == this : false;
+	public readonly bool Equals(Mat3<T> other)
+		=> this == other;
+}
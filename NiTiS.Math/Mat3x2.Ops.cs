@@ -0,0 +1,123 @@
+﻿using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class Mat3x2
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> Add<T>(Mat3x2<T> left, Mat3x2<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left + right;
+
+	public static Mat3x2<T> CreateRotation<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> CreateRotation(angle, Vector2D<T>.Zero);
+	public static Mat3x2<T> CreateRotation<T>(Angle<T> angle, Vector2D<T> centerPoint)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T c = T.Cos(angle.Radians), s = T.Sin(angle.Radians);
+
+		// [  c  s ]
+		// [ -s  c ]
+		// [  x  y ]
+		return new(
+			c, s,
+			-s, c,
+			centerPoint.X * (T.One - c) + centerPoint.Y * s,
+			centerPoint.Y * (T.One - c) - centerPoint.X * s
+			);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> CreateScale<T>(T scale)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(new Vector2D<T>(scale), Vector2D<T>.Zero);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> CreateScale<T>(T scale, Vector2D<T> centerPoint)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(new Vector2D<T>(scale), centerPoint);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> CreateScale<T>(Vector2D<T> scales)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(scales, Vector2D<T>.Zero);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> CreateScale<T>(Vector2D<T> scales, Vector2D<T> centerPoint)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			scales.X, T.Zero,
+			T.Zero, scales.Y,
+			centerPoint.X * (T.One - scales.X),
+			centerPoint.Y * (T.One - scales.Y)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> CreateTranslation<T>(Vector2D<T> position)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			T.One, T.Zero,
+			T.Zero, T.One,
+			position.X, position.Y
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Determinant<T>(Mat3x2<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> matrix.M11 * matrix.M22 - matrix.M21 * matrix.M12;
+
+	/// <summary>Attempts to invert the given matrix.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat3x2<T> matrix, out Mat3x2<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+		=> Invert(matrix, T.Zero, out result);
+	/// <summary>Attempts to invert the given matrix, treating it as singular when the absolute value of its determinant is not greater than <paramref name="epsilon" />.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="epsilon">The largest absolute determinant still considered singular, it is absolute rather than scaled by the matrix norm, so choose it for the expected magnitude of the determinant.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat3x2<T> matrix, T epsilon, out Mat3x2<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+	{
+		T det = Determinant(matrix);
+
+		if (!(T.Abs(det) > epsilon))
+		{
+			result = default;
+			return false;
+		}
+
+		T invDet = T.One / det;
+
+		result = new(
+			matrix.M22 * invDet,
+			-matrix.M12 * invDet,
+			-matrix.M21 * invDet,
+			matrix.M11 * invDet,
+			(matrix.M21 * matrix.M32 - matrix.M31 * matrix.M22) * invDet,
+			(matrix.M31 * matrix.M12 - matrix.M11 * matrix.M32) * invDet
+			);
+		return true;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> Multiply<T>(Mat3x2<T> left, Mat3x2<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> Multiply<T>(Mat3x2<T> left, T right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> Negate<T>(Mat3x2<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> -operand;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> Subtract<T>(Mat3x2<T> left, Mat3x2<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left - right;
+}
@@ -0,0 +1,166 @@
+﻿using NiTiS.Core.Operators;
+using System;
+using System.Diagnostics.CodeAnalysis;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Affine 2D transform, layout-compatible with <see cref="Matrix3x2" /> for <see langword="float" />.
+/// </summary>
+public readonly unsafe struct Mat3x2<T> :
+	// Matrix op Matrix
+	IAdditionOperators<Mat3x2<T>, Mat3x2<T>, Mat3x2<T>>,
+	ISubtractionOperators<Mat3x2<T>, Mat3x2<T>, Mat3x2<T>>,
+	IMultiplyOperators<Mat3x2<T>, Mat3x2<T>, Mat3x2<T>>,
+	IEqualityOperators<Mat3x2<T>, Mat3x2<T>, bool>,
+	// Matrix op T
+	IMultiplyOperators<Mat3x2<T>, T, Mat3x2<T>>,
+	// Unary op
+	IUnaryNegationOperators<Mat3x2<T>, Mat3x2<T>>,
+	IUnaryPlusOperators<Mat3x2<T>, Mat3x2<T>>,
+	IEquatable<Mat3x2<T>>,
+	// Cast op
+	IExplicitCastOperators<Mat3x2<T>, Mat4<T>>,
+	IExplicitCastOperators<Mat3x2<T>, Mat3<T>>
+	where T : unmanaged, INumberBase<T>
+{
+	#region Matrix
+	public readonly T M11;
+	public readonly T M12;
+
+	public readonly T M21;
+	public readonly T M22;
+
+	public readonly T M31;
+	public readonly T M32;
+	#endregion
+	public Vector2D<T> Translation
+		=> new(M31, M32);
+	public static Mat3x2<T> Identity => new(
+		T.One, T.Zero,
+		T.Zero, T.One,
+		T.Zero, T.Zero
+		);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public Mat3x2(
+		T m11, T m12,
+		T m21, T m22,
+		T m31, T m32
+		)
+	{
+		M11 = m11;
+		M12 = m12;
+		M21 = m21;
+		M22 = m22;
+		M31 = m31;
+		M32 = m32;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> operator +(Mat3x2<T> left, Mat3x2<T> right)
+		=> new(
+			left.M11 + right.M11, left.M12 + right.M12,
+			left.M21 + right.M21, left.M22 + right.M22,
+			left.M31 + right.M31, left.M32 + right.M32
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> operator -(Mat3x2<T> left, Mat3x2<T> right)
+		=> new(
+			left.M11 - right.M11, left.M12 - right.M12,
+			left.M21 - right.M21, left.M22 - right.M22,
+			left.M31 - right.M31, left.M32 - right.M32
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> operator *(Mat3x2<T> left, Mat3x2<T> right)
+		=> new(
+			left.M11 * right.M11 + left.M12 * right.M21,
+			left.M11 * right.M12 + left.M12 * right.M22,
+
+			left.M21 * right.M11 + left.M22 * right.M21,
+			left.M21 * right.M12 + left.M22 * right.M22,
+
+			left.M31 * right.M11 + left.M32 * right.M21 + right.M31,
+			left.M31 * right.M12 + left.M32 * right.M22 + right.M32
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(Mat3x2<T> left, Mat3x2<T> right)
+		=> left.M11 == right.M11 && left.M12 == right.M12
+		&& left.M21 == right.M21 && left.M22 == right.M22
+		&& left.M31 == right.M31 && left.M32 == right.M32;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(Mat3x2<T> left, Mat3x2<T> right)
+		=> !(left == right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> operator *(Mat3x2<T> left, T right)
+		=> new(
+			left.M11 * right, left.M12 * right,
+			left.M21 * right, left.M22 * right,
+			left.M31 * right, left.M32 * right
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> operator *(T left, Mat3x2<T> right)
+		=> right * left;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> operator -(Mat3x2<T> operand)
+		=> new(
+			-operand.M11, -operand.M12,
+			-operand.M21, -operand.M22,
+			-operand.M31, -operand.M32
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat3x2<T> operator +(Mat3x2<T> operand)
+		=> operand;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat4<T>(Mat3x2<T> operand)
+		=> new(
+			operand.M11, operand.M12, T.Zero, T.Zero,
+			operand.M21, operand.M22, T.Zero, T.Zero,
+			T.Zero, T.Zero, T.One, T.Zero,
+			operand.M31, operand.M32, T.Zero, T.One
+			);
+	/// <summary>Takes the XY part of a 3D affine matrix, dropping everything related to Z.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat3x2<T>(Mat4<T> operand)
+		=> new(
+			operand.M11, operand.M12,
+			operand.M21, operand.M22,
+			operand.M41, operand.M42
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static explicit operator Mat3<T>(Mat3x2<T> operand)
+		=> new(
+			operand.M11, operand.M12, T.Zero,
+			operand.M21, operand.M22, T.Zero,
+			operand.M31, operand.M32, T.One
+			);
+
+	[MethodImpl(AggressiveOptimization | AggressiveInlining)]
+	public static Mat3x2<float> ConvertFromSystem(Matrix3x2 matrix)
+		=> *((Mat3x2<float>*)&matrix);
+	[MethodImpl(AggressiveOptimization | AggressiveInlining)]
+	public static Matrix3x2 ConvertToSystem(Mat3x2<float> matrix)
+		=> *((Matrix3x2*)&matrix);
+
+	/// <summary>Indicates whether the current matrix is the identity matrix.</summary>
+	/// <value><see langword="true" /> if the current matrix is the identity matrix; otherwise, <see langword="false" />.</value>
+	public readonly bool IsIdentity
+		=> M11 == T.One && M22 == T.One
+		&& M12 == T.Zero && M21 == T.Zero
+		&& M31 == T.Zero && M32 == T.Zero;
+
+	public override readonly int GetHashCode()
+		=> HashCode.Combine(M11, M12, M21, M22, M31, M32);
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Mat3x2<T> mat
+		? mat == this : false;
+	public readonly bool Equals(Mat3x2<T> other)
+		=> this == other;
+}
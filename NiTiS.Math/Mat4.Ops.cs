@@ -1,22 +1,44 @@
-﻿using NiTiS.Core.Annotations;
+﻿using NiTiS.Math.LinearAlgebra;
 using System;
 using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
 
 public static unsafe class Mat4
 {
-	public static Mat4<float> CreateBillboard(Vector3D<float> objPos, Vector3D<float> cameraPos, Vector3D<float> cameraUp, Vector3D<float> cameraForward)
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> Add<T>(Mat4<T> left, Mat4<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left + right;
+
+	public static bool ApproximatelyEquals<T>(Mat4<T> left, Mat4<T> right, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
 	{
-		const float epsilon = 1e-4f;
+		ReadOnlySpan<T> a = MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in left.M11), 16);
+		ReadOnlySpan<T> b = MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in right.M11), 16);
 
-		Vector3D<float> zaxis = new(
-			objPos.X - cameraPos.X,
-			objPos.Y - cameraPos.Y,
-			objPos.Z - cameraPos.Z
-			);
+		for (int i = 0; i < a.Length; i++)
+		{
+			if (!tolerance.AreEqual(a[i], b[i]))
+				return false;
+		}
+
+		return true;
+	}
+
+	public static Mat4<T> CreateBillboard<T>(Vector3D<T> objPos, Vector3D<T> cameraPos, Vector3D<T> cameraUp, Vector3D<T> cameraForward)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> CreateBillboard(objPos, cameraPos, cameraUp, cameraForward, T.CreateTruncating(1e-4));
+	/// <param name="epsilon">Squared distance between object and camera below which <paramref name="cameraForward" /> is used as the facing direction.</param>
+	public static Mat4<T> CreateBillboard<T>(Vector3D<T> objPos, Vector3D<T> cameraPos, Vector3D<T> cameraUp, Vector3D<T> cameraForward, T epsilon)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Vector3D<T> zaxis = objPos - cameraPos;
 
-		float norm = zaxis.LengthSquared;
+		T norm = zaxis.LengthSquared;
 
 		if (norm < epsilon)
 		{
@@ -24,11 +46,10 @@ public static unsafe class Mat4
 		}
 		else
 		{
-			zaxis = Vector3D.Multiply(zaxis, 1f / (float)SMath.Sqrt(norm));
+			zaxis = Vector3D.Multiply(zaxis, T.One / T.Sqrt(norm));
 		}
 
-		Vector3D<float> xaxis, yaxis;
-
+		Vector3D<T> xaxis, yaxis;
 
 		xaxis = Vector3D.Normalize(Vector3D.Cross(cameraUp, zaxis));
 
@@ -38,25 +59,610 @@ public static unsafe class Mat4
 				m11: xaxis.X,
 				m12: xaxis.Y,
 				m13: xaxis.Z,
-				m14: 0f,
+				m14: T.Zero,
+				m21: yaxis.X,
+				m22: yaxis.Y,
+				m23: yaxis.Z,
+				m24: T.Zero,
+				m31: zaxis.X,
+				m32: zaxis.Y,
+				m33: zaxis.Z,
+				m34: T.Zero,
+				m41: objPos.X,
+				m42: objPos.Y,
+				m43: objPos.Z,
+				m44: T.One
+				);
+	}
+
+	public static Mat4<T> CreateConstrainedBillboard<T>(Vector3D<T> objPos, Vector3D<T> cameraPos, Vector3D<T> rotateAxis, Vector3D<T> cameraForward, Vector3D<T> objForward)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> CreateConstrainedBillboard(objPos, cameraPos, rotateAxis, cameraForward, objForward, T.CreateTruncating(1e-4));
+	/// <summary>Creates a matrix that rotates around <paramref name="rotateAxis" /> to face the camera.</summary>
+	/// <param name="objForward">Facing direction used when the camera direction is (almost) parallel to <paramref name="rotateAxis" />.</param>
+	/// <param name="epsilon">Squared distance between object and camera below which <paramref name="cameraForward" /> is used as the facing direction.</param>
+	public static Mat4<T> CreateConstrainedBillboard<T>(Vector3D<T> objPos, Vector3D<T> cameraPos, Vector3D<T> rotateAxis, Vector3D<T> cameraForward, Vector3D<T> objForward, T epsilon)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		// Mirrors the threshold of System.Numerics, 1 - 0.1 degrees in radians (about cos(3.4 degrees)) rather than cos(0.1 degrees)
+		T minAngle = T.One - (T.CreateTruncating(0.1) * (T.Pi / T.CreateTruncating(180)));
+
+		Vector3D<T> faceDir = objPos - cameraPos;
+
+		T norm = faceDir.LengthSquared;
+
+		if (norm < epsilon)
+		{
+			faceDir = -cameraForward;
+		}
+		else
+		{
+			faceDir = Vector3D.Multiply(faceDir, T.One / T.Sqrt(norm));
+		}
+
+		Vector3D<T> yaxis = rotateAxis;
+		Vector3D<T> xaxis, zaxis;
+
+		T dot = Vector3D.Dot(rotateAxis, faceDir);
+
+		if (T.Abs(dot) > minAngle)
+		{
+			zaxis = objForward;
+
+			dot = Vector3D.Dot(rotateAxis, zaxis);
+
+			if (T.Abs(dot) > minAngle)
+			{
+				zaxis = T.Abs(rotateAxis.Z) > minAngle
+					? Vector3D<T>.UnitX
+					: -Vector3D<T>.UnitZ;
+			}
+
+			xaxis = Vector3D.Normalize(Vector3D.Cross(rotateAxis, zaxis));
+			zaxis = Vector3D.Normalize(Vector3D.Cross(xaxis, rotateAxis));
+		}
+		else
+		{
+			xaxis = Vector3D.Normalize(Vector3D.Cross(rotateAxis, faceDir));
+			zaxis = Vector3D.Normalize(Vector3D.Cross(xaxis, yaxis));
+		}
+
+		return new(
+				m11: xaxis.X,
+				m12: xaxis.Y,
+				m13: xaxis.Z,
+				m14: T.Zero,
 				m21: yaxis.X,
 				m22: yaxis.Y,
 				m23: yaxis.Z,
-				m24: 0f,
+				m24: T.Zero,
 				m31: zaxis.X,
 				m32: zaxis.Y,
 				m33: zaxis.Z,
-				m34: 0f,
+				m34: T.Zero,
 				m41: objPos.X,
 				m42: objPos.Y,
 				m43: objPos.Z,
-				m44: 1f
+				m44: T.One
 				);
 	}
-	[NotImplementYet]
-	[Obsolete(nameof(NotImplementYetAttribute))]
-	public static Mat4<float> CreateConstrainedBillboard()
-		=> default;
+
+	public static Mat4<T> CreateFromAxisAngle<T>(Vector3D<T> axis, Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T x = axis.X, y = axis.Y, z = axis.Z;
+		T sa = T.Sin(angle.Radians), ca = T.Cos(angle.Radians);
+		T xx = x * x, yy = y * y, zz = z * z;
+		T xy = x * y, xz = x * z, yz = y * z;
+
+		return new(
+			m11: xx + ca * (T.One - xx),
+			m12: xy - ca * xy + sa * z,
+			m13: xz - ca * xz - sa * y,
+			m14: T.Zero,
+			m21: xy - ca * xy - sa * z,
+			m22: yy + ca * (T.One - yy),
+			m23: yz - ca * yz + sa * x,
+			m24: T.Zero,
+			m31: xz - ca * xz + sa * y,
+			m32: yz - ca * yz - sa * x,
+			m33: zz + ca * (T.One - zz),
+			m34: T.Zero,
+			m41: T.Zero,
+			m42: T.Zero,
+			m43: T.Zero,
+			m44: T.One
+			);
+	}
+
+	/// <summary>Creates a rigid transform matrix from the given unit dual quaternion.</summary>
+	public static Mat4<T> CreateFromDualQuaternion<T>(DualQuaternion<T> transform)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Mat4<T> rotation = CreateFromQuaternion(transform.Real);
+		Vector3D<T> translation = DualQuaternion.GetTranslation(transform);
+
+		return new(
+			rotation.M11, rotation.M12, rotation.M13, T.Zero,
+			rotation.M21, rotation.M22, rotation.M23, T.Zero,
+			rotation.M31, rotation.M32, rotation.M33, T.Zero,
+			translation.X, translation.Y, translation.Z, T.One
+			);
+	}
+
+	/// <summary>Creates a rotation matrix from Euler angles.</summary>
+	/// <param name="first">Angle around the first axis of <paramref name="order" />.</param>
+	/// <param name="second">Angle around the second axis of <paramref name="order" />.</param>
+	/// <param name="third">Angle around the third axis of <paramref name="order" />.</param>
+	/// <param name="order">Sequence of rotation axes.</param>
+	/// <param name="frame">Whether the axes are fixed or move with the rotated frame.</param>
+	public static Mat4<T> CreateFromEulerAngles<T>(Angle<T> first, Angle<T> second, Angle<T> third, EulerOrder order, EulerFrame frame)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		GetEulerAxes(order, out int firstAxis, out int secondAxis, out int thirdAxis);
+
+		Mat4<T> a = CreateRotation(firstAxis, first);
+		Mat4<T> b = CreateRotation(secondAxis, second);
+		Mat4<T> c = CreateRotation(thirdAxis, third);
+
+		// Row vectors are transformed left to right, intrinsic rotations compose in reverse
+		return frame == EulerFrame.Extrinsic
+			? a * b * c
+			: c * b * a;
+	}
+
+	/// <summary>Creates a rotation matrix from the given quaternion.</summary>
+	public static Mat4<T> CreateFromQuaternion<T>(Quaternion<T> quaternion)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T two = Scalar<T>.Two;
+		T x = quaternion.X, y = quaternion.Y, z = quaternion.Z, w = quaternion.W;
+
+		T xx = x * x, yy = y * y, zz = z * z;
+		T xy = x * y, wz = z * w, xz = z * x;
+		T wy = y * w, yz = y * z, wx = x * w;
+
+		return new(
+			m11: T.One - two * (yy + zz),
+			m12: two * (xy + wz),
+			m13: two * (xz - wy),
+			m14: T.Zero,
+			m21: two * (xy - wz),
+			m22: T.One - two * (zz + xx),
+			m23: two * (yz + wx),
+			m24: T.Zero,
+			m31: two * (xz + wy),
+			m32: two * (yz - wx),
+			m33: T.One - two * (yy + xx),
+			m34: T.Zero,
+			m41: T.Zero,
+			m42: T.Zero,
+			m43: T.Zero,
+			m44: T.One
+			);
+	}
+
+	public static Mat4<T> CreateFromYawPitchRoll<T>(Angle<T> yaw, Angle<T> pitch, Angle<T> roll)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> CreateFromQuaternion(Quaternion.CreateFromYawPitchRoll(yaw, pitch, roll));
+
+	/// <summary>Creates a view matrix. Only <see cref="ProjectionOptions.LeftHanded" /> is taken from <paramref name="options" />.</summary>
+	public static Mat4<T> CreateLookAt<T>(Vector3D<T> cameraPosition, Vector3D<T> cameraTarget, Vector3D<T> cameraUpVector, ProjectionOptions options = ProjectionOptions.None)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Vector3D<T> zaxis = (options & ProjectionOptions.LeftHanded) != 0
+			? Vector3D.Normalize(cameraTarget - cameraPosition)
+			: Vector3D.Normalize(cameraPosition - cameraTarget);
+		Vector3D<T> xaxis = Vector3D.Normalize(Vector3D.Cross(cameraUpVector, zaxis));
+		Vector3D<T> yaxis = Vector3D.Cross(zaxis, xaxis);
+
+		return new(
+			m11: xaxis.X,
+			m12: yaxis.X,
+			m13: zaxis.X,
+			m14: T.Zero,
+			m21: xaxis.Y,
+			m22: yaxis.Y,
+			m23: zaxis.Y,
+			m24: T.Zero,
+			m31: xaxis.Z,
+			m32: yaxis.Z,
+			m33: zaxis.Z,
+			m34: T.Zero,
+			m41: -Vector3D.Dot(xaxis, cameraPosition),
+			m42: -Vector3D.Dot(yaxis, cameraPosition),
+			m43: -Vector3D.Dot(zaxis, cameraPosition),
+			m44: T.One
+			);
+	}
+
+	public static Mat4<T> CreateOrthographic<T>(T width, T height, T zNearPlane, T zFarPlane, ProjectionOptions options = ProjectionOptions.None)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		GetOrthographicDepth(zNearPlane, zFarPlane, options, out T m33, out T m43);
+
+		return new(
+			m11: Scalar<T>.Two / width,
+			m12: T.Zero,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: Scalar<T>.Two / height,
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: T.Zero,
+			m32: T.Zero,
+			m33: m33,
+			m34: T.Zero,
+			m41: T.Zero,
+			m42: T.Zero,
+			m43: m43,
+			m44: T.One
+			);
+	}
+
+	public static Mat4<T> CreateOrthographicOffCenter<T>(T left, T right, T bottom, T top, T zNearPlane, T zFarPlane, ProjectionOptions options = ProjectionOptions.None)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		GetOrthographicDepth(zNearPlane, zFarPlane, options, out T m33, out T m43);
+
+		return new(
+			m11: Scalar<T>.Two / (right - left),
+			m12: T.Zero,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: Scalar<T>.Two / (top - bottom),
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: T.Zero,
+			m32: T.Zero,
+			m33: m33,
+			m34: T.Zero,
+			m41: (left + right) / (left - right),
+			m42: (top + bottom) / (bottom - top),
+			m43: m43,
+			m44: T.One
+			);
+	}
+
+	/// <remarks>Pass <see cref="IFloatingPointIeee754{TSelf}.PositiveInfinity" /> as <paramref name="farPlaneDistance" /> for an infinite far plane.</remarks>
+	public static Mat4<T> CreatePerspective<T>(T width, T height, T nearPlaneDistance, T farPlaneDistance, ProjectionOptions options = ProjectionOptions.None)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		GetPerspectiveDepth(nearPlaneDistance, farPlaneDistance, options, out T m33, out T m34, out T m43);
+
+		T dblNear = nearPlaneDistance + nearPlaneDistance;
+
+		return new(
+			m11: dblNear / width,
+			m12: T.Zero,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: dblNear / height,
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: T.Zero,
+			m32: T.Zero,
+			m33: m33,
+			m34: m34,
+			m41: T.Zero,
+			m42: T.Zero,
+			m43: m43,
+			m44: T.Zero
+			);
+	}
+
+	/// <remarks>Pass <see cref="IFloatingPointIeee754{TSelf}.PositiveInfinity" /> as <paramref name="farPlaneDistance" /> for an infinite far plane.</remarks>
+	public static Mat4<T> CreatePerspectiveFieldOfView<T>(Angle<T> fieldOfView, T aspectRatio, T nearPlaneDistance, T farPlaneDistance, ProjectionOptions options = ProjectionOptions.None)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		if (fieldOfView <= Angle<T>.Zero || fieldOfView >= Angle<T>.HalfTurn)
+			throw new ArgumentOutOfRangeException(nameof(fieldOfView));
+
+		GetPerspectiveDepth(nearPlaneDistance, farPlaneDistance, options, out T m33, out T m34, out T m43);
+
+		T yScale = T.One / T.Tan(fieldOfView.Radians / Scalar<T>.Two);
+		T xScale = yScale / aspectRatio;
+
+		return new(
+			m11: xScale,
+			m12: T.Zero,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: yScale,
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: T.Zero,
+			m32: T.Zero,
+			m33: m33,
+			m34: m34,
+			m41: T.Zero,
+			m42: T.Zero,
+			m43: m43,
+			m44: T.Zero
+			);
+	}
+
+	/// <remarks>Pass <see cref="IFloatingPointIeee754{TSelf}.PositiveInfinity" /> as <paramref name="farPlaneDistance" /> for an infinite far plane.</remarks>
+	public static Mat4<T> CreatePerspectiveOffCenter<T>(T left, T right, T bottom, T top, T nearPlaneDistance, T farPlaneDistance, ProjectionOptions options = ProjectionOptions.None)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		GetPerspectiveDepth(nearPlaneDistance, farPlaneDistance, options, out T m33, out T m34, out T m43);
+
+		T dblNear = nearPlaneDistance + nearPlaneDistance;
+
+		// m34 is -1 for right-handed and +1 for left-handed projections
+		return new(
+			m11: dblNear / (right - left),
+			m12: T.Zero,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: dblNear / (top - bottom),
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: -m34 * (left + right) / (right - left),
+			m32: -m34 * (top + bottom) / (top - bottom),
+			m33: m33,
+			m34: m34,
+			m41: T.Zero,
+			m42: T.Zero,
+			m43: m43,
+			m44: T.Zero
+			);
+	}
+
+	private static void GetOrthographicDepth<T>(T zNearPlane, T zFarPlane, ProjectionOptions options, out T m33, out T m43)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		// Maps view distance d in [near, far] to depth a * d + b in [0, 1]
+		T range = zFarPlane - zNearPlane;
+		T a = T.One / range;
+		T b = -(zNearPlane / range);
+
+		if ((options & ProjectionOptions.ReversedZ) != 0)
+			(a, b) = (-a, T.One - b);
+		if ((options & ProjectionOptions.NegativeOneToOneDepth) != 0)
+			(a, b) = (Scalar<T>.Two * a, Scalar<T>.Two * b - T.One);
+
+		m33 = (options & ProjectionOptions.LeftHanded) != 0 ? a : -a;
+		m43 = b;
+	}
+	private static void GetPerspectiveDepth<T>(T nearPlaneDistance, T farPlaneDistance, ProjectionOptions options, out T m33, out T m34, out T m43)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		if (nearPlaneDistance <= T.Zero)
+			throw new ArgumentOutOfRangeException(nameof(nearPlaneDistance));
+		if (farPlaneDistance <= T.Zero)
+			throw new ArgumentOutOfRangeException(nameof(farPlaneDistance));
+		if (nearPlaneDistance >= farPlaneDistance)
+			throw new ArgumentOutOfRangeException(nameof(nearPlaneDistance));
+
+		// Maps view distance d in [near, far] to clip depth a * d + b, with w = d, so that depth / w is in [0, 1]
+		T a = T.IsPositiveInfinity(farPlaneDistance)
+			? T.One
+			: farPlaneDistance / (farPlaneDistance - nearPlaneDistance);
+		T b = -(nearPlaneDistance * a);
+
+		if ((options & ProjectionOptions.ReversedZ) != 0)
+			(a, b) = (T.One - a, -b);
+		if ((options & ProjectionOptions.NegativeOneToOneDepth) != 0)
+			(a, b) = (Scalar<T>.Two * a - T.One, Scalar<T>.Two * b);
+
+		if ((options & ProjectionOptions.LeftHanded) != 0)
+		{
+			m33 = a;
+			m34 = T.One;
+		}
+		else
+		{
+			m33 = -a;
+			m34 = T.NegativeOne;
+		}
+		m43 = b;
+	}
+
+	public static Mat4<T> CreateReflection<T>(Plane<T> plane)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		plane = Plane.Normalize(plane);
+
+		T a = plane.Normal.X, b = plane.Normal.Y, c = plane.Normal.Z;
+		T fa = -Scalar<T>.Two * a;
+		T fb = -Scalar<T>.Two * b;
+		T fc = -Scalar<T>.Two * c;
+
+		return new(
+			m11: fa * a + T.One,
+			m12: fb * a,
+			m13: fc * a,
+			m14: T.Zero,
+			m21: fa * b,
+			m22: fb * b + T.One,
+			m23: fc * b,
+			m24: T.Zero,
+			m31: fa * c,
+			m32: fb * c,
+			m33: fc * c + T.One,
+			m34: T.Zero,
+			m41: fa * plane.D,
+			m42: fb * plane.D,
+			m43: fc * plane.D,
+			m44: T.One
+			);
+	}
+
+	public static Mat4<T> CreateRotationX<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> CreateRotationX(angle, Vector3D<T>.Zero);
+	public static Mat4<T> CreateRotationX<T>(Angle<T> angle, Vector3D<T> centerPoint)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T c = T.Cos(angle.Radians), s = T.Sin(angle.Radians);
+
+		// [  1  0  0  0 ]
+		// [  0  c  s  0 ]
+		// [  0 -s  c  0 ]
+		// [  0  y  z  1 ]
+		return new(
+			m11: T.One,
+			m12: T.Zero,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: c,
+			m23: s,
+			m24: T.Zero,
+			m31: T.Zero,
+			m32: -s,
+			m33: c,
+			m34: T.Zero,
+			m41: T.Zero,
+			m42: centerPoint.Y * (T.One - c) + centerPoint.Z * s,
+			m43: centerPoint.Z * (T.One - c) - centerPoint.Y * s,
+			m44: T.One
+			);
+	}
+
+	public static Mat4<T> CreateRotationY<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> CreateRotationY(angle, Vector3D<T>.Zero);
+	public static Mat4<T> CreateRotationY<T>(Angle<T> angle, Vector3D<T> centerPoint)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T c = T.Cos(angle.Radians), s = T.Sin(angle.Radians);
+
+		// [  c  0 -s  0 ]
+		// [  0  1  0  0 ]
+		// [  s  0  c  0 ]
+		// [  x  0  z  1 ]
+		return new(
+			m11: c,
+			m12: T.Zero,
+			m13: -s,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: T.One,
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: s,
+			m32: T.Zero,
+			m33: c,
+			m34: T.Zero,
+			m41: centerPoint.X * (T.One - c) - centerPoint.Z * s,
+			m42: T.Zero,
+			m43: centerPoint.Z * (T.One - c) + centerPoint.X * s,
+			m44: T.One
+			);
+	}
+
+	public static Mat4<T> CreateRotationZ<T>(Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> CreateRotationZ(angle, Vector3D<T>.Zero);
+	public static Mat4<T> CreateRotationZ<T>(Angle<T> angle, Vector3D<T> centerPoint)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T c = T.Cos(angle.Radians), s = T.Sin(angle.Radians);
+
+		// [  c  s  0  0 ]
+		// [ -s  c  0  0 ]
+		// [  0  0  1  0 ]
+		// [  x  y  0  1 ]
+		return new(
+			m11: c,
+			m12: s,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: -s,
+			m22: c,
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: T.Zero,
+			m32: T.Zero,
+			m33: T.One,
+			m34: T.Zero,
+			m41: centerPoint.X * (T.One - c) + centerPoint.Y * s,
+			m42: centerPoint.Y * (T.One - c) - centerPoint.X * s,
+			m43: T.Zero,
+			m44: T.One
+			);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> CreateScale<T>(T scale)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(new Vector3D<T>(scale));
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> CreateScale<T>(T scale, Vector3D<T> centerPoint)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(new Vector3D<T>(scale), centerPoint);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> CreateScale<T>(T xScale, T yScale, T zScale)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(new Vector3D<T>(xScale, yScale, zScale));
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> CreateScale<T>(T xScale, T yScale, T zScale, Vector3D<T> centerPoint)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(new Vector3D<T>(xScale, yScale, zScale), centerPoint);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> CreateScale<T>(Vector3D<T> scales)
+		where T : unmanaged, INumberBase<T>
+		=> CreateScale(scales, Vector3D<T>.Zero);
+	public static Mat4<T> CreateScale<T>(Vector3D<T> scales, Vector3D<T> centerPoint)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			m11: scales.X,
+			m12: T.Zero,
+			m13: T.Zero,
+			m14: T.Zero,
+			m21: T.Zero,
+			m22: scales.Y,
+			m23: T.Zero,
+			m24: T.Zero,
+			m31: T.Zero,
+			m32: T.Zero,
+			m33: scales.Z,
+			m34: T.Zero,
+			m41: centerPoint.X * (T.One - scales.X),
+			m42: centerPoint.Y * (T.One - scales.Y),
+			m43: centerPoint.Z * (T.One - scales.Z),
+			m44: T.One
+			);
+
+	public static Mat4<T> CreateShadow<T>(Vector3D<T> lightDirection, Plane<T> plane)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Plane<T> p = Plane.Normalize(plane);
+
+		T dot = Vector3D.Dot(p.Normal, lightDirection);
+
+		T a = -p.Normal.X;
+		T b = -p.Normal.Y;
+		T c = -p.Normal.Z;
+		T d = -p.D;
+
+		return new(
+			m11: a * lightDirection.X + dot,
+			m12: a * lightDirection.Y,
+			m13: a * lightDirection.Z,
+			m14: T.Zero,
+			m21: b * lightDirection.X,
+			m22: b * lightDirection.Y + dot,
+			m23: b * lightDirection.Z,
+			m24: T.Zero,
+			m31: c * lightDirection.X,
+			m32: c * lightDirection.Y,
+			m33: c * lightDirection.Z + dot,
+			m34: T.Zero,
+			m41: d * lightDirection.X,
+			m42: d * lightDirection.Y,
+			m43: d * lightDirection.Z,
+			m44: dot
+			);
+	}
 
 	public static Mat4<T> CreateTranslation<T>(Vector3D<T> position)
 		where T : unmanaged, INumberBase<T>
@@ -78,4 +684,434 @@ public static unsafe class Mat4
 			m43: position.Z,
 			m44: T.One
 			);
+
+	/// <summary>Creates a matrix that scales, then rotates, then translates.</summary>
+	/// <param name="rotation">Rotation matrix; its translation and projective parts are ignored.</param>
+	public static Mat4<T> CreateTRS<T>(Vector3D<T> translation, Mat4<T> rotation, Vector3D<T> scale)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			m11: scale.X * rotation.M11,
+			m12: scale.X * rotation.M12,
+			m13: scale.X * rotation.M13,
+			m14: T.Zero,
+			m21: scale.Y * rotation.M21,
+			m22: scale.Y * rotation.M22,
+			m23: scale.Y * rotation.M23,
+			m24: T.Zero,
+			m31: scale.Z * rotation.M31,
+			m32: scale.Z * rotation.M32,
+			m33: scale.Z * rotation.M33,
+			m34: T.Zero,
+			m41: translation.X,
+			m42: translation.Y,
+			m43: translation.Z,
+			m44: T.One
+			);
+
+	/// <summary>Decomposes an affine matrix into scale, an orthonormal rotation and translation.</summary>
+	/// <returns><see langword="true" /> if <paramref name="matrix" /> is exactly represented by the outputs, allowing negative scale; otherwise, <see langword="false" />.</returns>
+	public static bool Decompose<T>(Mat4<T> matrix, out Vector3D<T> scale, out Mat4<T> rotation, out Vector3D<T> translation)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> (Decompose(matrix, T.CreateTruncating(1e-4), out scale, out rotation, out translation) & ~DecompositionFlags.NegativeScale) == DecompositionFlags.None;
+	/// <summary>Decomposes an affine matrix into scale, an orthonormal rotation and translation.</summary>
+	/// <param name="epsilon">Tolerance for zero-length axes and for the cosine between axes.</param>
+	/// <returns>Flags describing how <paramref name="matrix" /> differs from a plain scale, rotation and translation.</returns>
+	public static DecompositionFlags Decompose<T>(Mat4<T> matrix, T epsilon, out Vector3D<T> scale, out Mat4<T> rotation, out Vector3D<T> translation)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		DecompositionFlags flags = DecompositionFlags.None;
+
+		if (!matrix.IsAffine)
+			flags |= DecompositionFlags.Projective;
+
+		translation = matrix.Translation;
+
+		Vector3D<T> xaxis = new(matrix.M11, matrix.M12, matrix.M13);
+		Vector3D<T> yaxis = new(matrix.M21, matrix.M22, matrix.M23);
+		Vector3D<T> zaxis = new(matrix.M31, matrix.M32, matrix.M33);
+
+		T sx = Vector3D.Length(xaxis);
+		T sy = Vector3D.Length(yaxis);
+		T sz = Vector3D.Length(zaxis);
+
+		if (!(sx > epsilon && sy > epsilon && sz > epsilon))
+		{
+			scale = new(sx, sy, sz);
+			rotation = Mat4<T>.Identity;
+			return flags | DecompositionFlags.Degenerate;
+		}
+
+		xaxis /= sx;
+		yaxis /= sy;
+		zaxis /= sz;
+
+		T xy = Vector3D.Dot(xaxis, yaxis);
+		T xz = Vector3D.Dot(xaxis, zaxis);
+		T yz = Vector3D.Dot(yaxis, zaxis);
+
+		if (T.Abs(xy) > epsilon || T.Abs(xz) > epsilon || T.Abs(yz) > epsilon)
+		{
+			flags |= DecompositionFlags.Shear;
+
+			// Gram-Schmidt, keeping the X axis as is
+			yaxis = Vector3D.Normalize(yaxis - xaxis * xy);
+			zaxis = Vector3D.Normalize(zaxis - xaxis * xz - yaxis * Vector3D.Dot(yaxis, zaxis));
+		}
+
+		if (Vector3D.Dot(Vector3D.Cross(xaxis, yaxis), zaxis) < T.Zero)
+		{
+			flags |= DecompositionFlags.NegativeScale;
+
+			sx = -sx;
+			xaxis = -xaxis;
+		}
+
+		scale = new(sx, sy, sz);
+		rotation = new(
+			m11: xaxis.X,
+			m12: xaxis.Y,
+			m13: xaxis.Z,
+			m14: T.Zero,
+			m21: yaxis.X,
+			m22: yaxis.Y,
+			m23: yaxis.Z,
+			m24: T.Zero,
+			m31: zaxis.X,
+			m32: zaxis.Y,
+			m33: zaxis.Z,
+			m34: T.Zero,
+			m41: T.Zero,
+			m42: T.Zero,
+			m43: T.Zero,
+			m44: T.One
+			);
+		return flags;
+	}
+
+	/// <summary>Computes eigenvalues and eigenvectors of the given symmetric matrix with the Jacobi eigenvalue algorithm.</summary>
+	/// <param name="eigenvalues">Eigenvalues in descending order.</param>
+	/// <param name="eigenvectors">Orthonormal matrix whose columns are the eigenvectors, column <c>i</c> belongs to the <c>i</c>-th eigenvalue.</param>
+	public static void DecomposeSymmetricEigen<T>(Mat4<T> matrix, out Vector4D<T> eigenvalues, out Mat4<T> eigenvectors)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Span<T> a = stackalloc T[16]
+		{
+			matrix.M11, matrix.M12, matrix.M13, matrix.M14,
+			matrix.M21, matrix.M22, matrix.M23, matrix.M24,
+			matrix.M31, matrix.M32, matrix.M33, matrix.M34,
+			matrix.M41, matrix.M42, matrix.M43, matrix.M44,
+		};
+		Span<T> values = stackalloc T[4];
+		Span<T> v = stackalloc T[16];
+
+		Jacobi.EigenSymmetric(a, values, v, 4);
+
+		eigenvalues = new(values[0], values[1], values[2], values[3]);
+		// Jacobi yields eigenvectors as columns, matching SymmetricEigenDecomposition
+		eigenvectors = new(
+			v[0], v[1], v[2], v[3],
+			v[4], v[5], v[6], v[7],
+			v[8], v[9], v[10], v[11],
+			v[12], v[13], v[14], v[15]
+			);
+	}
+
+	public static T Determinant<T>(Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+	{
+		T a = matrix.M11, b = matrix.M12, c = matrix.M13, d = matrix.M14;
+		T e = matrix.M21, f = matrix.M22, g = matrix.M23, h = matrix.M24;
+		T i = matrix.M31, j = matrix.M32, k = matrix.M33, l = matrix.M34;
+		T m = matrix.M41, n = matrix.M42, o = matrix.M43, p = matrix.M44;
+
+		T kp_lo = k * p - l * o;
+		T jp_ln = j * p - l * n;
+		T jo_kn = j * o - k * n;
+		T ip_lm = i * p - l * m;
+		T io_km = i * o - k * m;
+		T in_jm = i * n - j * m;
+
+		return a * (f * kp_lo - g * jp_ln + h * jo_kn)
+			- b * (e * kp_lo - g * ip_lm + h * io_km)
+			+ c * (e * jp_ln - f * ip_lm + h * in_jm)
+			- d * (e * jo_kn - f * io_km + g * in_jm);
+	}
+
+	/// <summary>Extracts Euler angles from a rotation matrix, the inverse of <see cref="CreateFromEulerAngles{T}(Angle{T}, Angle{T}, Angle{T}, EulerOrder, EulerFrame)" />.</summary>
+	/// <remarks>
+	/// <paramref name="rotation" /> must be orthonormal, use <see cref="Decompose{T}(Mat4{T}, out Vector3D{T}, out Mat4{T}, out Vector3D{T})" /> to strip scale first.
+	/// In gimbal lock one outer angle is zero and the other carries the whole rotation around the aligned axes:
+	/// for <see cref="EulerFrame.Extrinsic" /> <paramref name="third" /> is zero and <paramref name="first" /> carries it,
+	/// for <see cref="EulerFrame.Intrinsic" /> <paramref name="first" /> is zero and <paramref name="third" /> carries it.
+	/// </remarks>
+	/// <param name="first">Angle around the first axis of <paramref name="order" />.</param>
+	/// <param name="second">Angle around the second axis of <paramref name="order" />, in [-pi/2, pi/2] for Tait-Bryan orders and in [0, pi] for proper Euler orders.</param>
+	/// <param name="third">Angle around the third axis of <paramref name="order" />.</param>
+	public static void ExtractEulerAngles<T>(Mat4<T> rotation, EulerOrder order, EulerFrame frame, out Angle<T> first, out Angle<T> second, out Angle<T> third)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		GetEulerAxes(order, out int i, out int j, out int k);
+
+		bool proper = i == k;
+		bool intrinsic = frame == EulerFrame.Intrinsic;
+
+		// Intrinsic rotations equal extrinsic ones around the reversed axis sequence
+		if (proper)
+			k = 3 - i - j;
+		else if (intrinsic)
+			(i, k) = (k, i);
+
+		// Column vector form of the rotation, the formulas below use q[row, column]
+		Span<T> q = stackalloc T[9]
+		{
+			rotation.M11, rotation.M21, rotation.M31,
+			rotation.M12, rotation.M22, rotation.M32,
+			rotation.M13, rotation.M23, rotation.M33,
+		};
+
+		T parity = (j - i + 3) % 3 == 1 ? T.One : -T.One;
+		T epsilon = T.CreateTruncating(16) * (T.BitIncrement(T.One) - T.One);
+		T a, b, c;
+
+		if (proper)
+		{
+			T sinB = T.Hypot(q[i * 3 + j], q[i * 3 + k]);
+			b = T.Atan2(sinB, q[i * 3 + i]);
+
+			if (sinB > epsilon)
+			{
+				a = T.Atan2(q[i * 3 + j], parity * q[i * 3 + k]);
+				c = T.Atan2(q[j * 3 + i], -parity * q[k * 3 + i]);
+			}
+			else
+			{
+				if (q[i * 3 + i] < T.Zero)
+					parity = -parity;
+
+				a = T.Atan2(parity * q[k * 3 + j], q[j * 3 + j]);
+				c = T.Zero;
+			}
+		}
+		else
+		{
+			T cosB = T.Hypot(q[k * 3 + k], q[k * 3 + j]);
+			b = T.Atan2(-parity * q[k * 3 + i], cosB);
+
+			if (cosB > epsilon)
+			{
+				a = T.Atan2(parity * q[k * 3 + j], q[k * 3 + k]);
+				c = T.Atan2(parity * q[j * 3 + i], q[i * 3 + i]);
+			}
+			else
+			{
+				a = T.Atan2(-parity * q[j * 3 + k], q[j * 3 + j]);
+				c = T.Zero;
+			}
+		}
+
+		(first, second, third) = intrinsic
+			? (Angle<T>.FromRadians(c), Angle<T>.FromRadians(b), Angle<T>.FromRadians(a))
+			: (Angle<T>.FromRadians(a), Angle<T>.FromRadians(b), Angle<T>.FromRadians(c));
+	}
+	private static void GetEulerAxes(EulerOrder order, out int first, out int second, out int third)
+	{
+		(first, second, third) = order switch
+		{
+			EulerOrder.XYZ => (0, 1, 2),
+			EulerOrder.XZY => (0, 2, 1),
+			EulerOrder.YXZ => (1, 0, 2),
+			EulerOrder.YZX => (1, 2, 0),
+			EulerOrder.ZXY => (2, 0, 1),
+			EulerOrder.ZYX => (2, 1, 0),
+			EulerOrder.XYX => (0, 1, 0),
+			EulerOrder.XZX => (0, 2, 0),
+			EulerOrder.YXY => (1, 0, 1),
+			EulerOrder.YZY => (1, 2, 1),
+			EulerOrder.ZXZ => (2, 0, 2),
+			EulerOrder.ZYZ => (2, 1, 2),
+			_ => throw new ArgumentOutOfRangeException(nameof(order)),
+		};
+	}
+	private static Mat4<T> CreateRotation<T>(int axis, Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> axis switch
+		{
+			0 => CreateRotationX(angle),
+			1 => CreateRotationY(angle),
+			_ => CreateRotationZ(angle),
+		};
+
+	/// <summary>Attempts to invert the given matrix.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat4<T> matrix, out Mat4<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+		=> Invert(matrix, T.Zero, out result);
+	/// <summary>Attempts to invert the given matrix, treating it as singular when the absolute value of its determinant is not greater than <paramref name="epsilon" />.</summary>
+	/// <param name="matrix">The matrix to invert.</param>
+	/// <param name="epsilon">The largest absolute determinant still considered singular, it is absolute rather than scaled by the matrix norm, so choose it for the expected magnitude of the determinant.</param>
+	/// <param name="result">The inverted matrix, or <see langword="default" /> if <paramref name="matrix" /> is singular.</param>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Mat4<T> matrix, T epsilon, out Mat4<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+	{
+		if (matrix.IsAffine)
+			return InvertAffine(matrix, epsilon, out result);
+
+		T a = matrix.M11, b = matrix.M12, c = matrix.M13, d = matrix.M14;
+		T e = matrix.M21, f = matrix.M22, g = matrix.M23, h = matrix.M24;
+		T i = matrix.M31, j = matrix.M32, k = matrix.M33, l = matrix.M34;
+		T m = matrix.M41, n = matrix.M42, o = matrix.M43, p = matrix.M44;
+
+		T kp_lo = k * p - l * o;
+		T jp_ln = j * p - l * n;
+		T jo_kn = j * o - k * n;
+		T ip_lm = i * p - l * m;
+		T io_km = i * o - k * m;
+		T in_jm = i * n - j * m;
+
+		T a11 = +(f * kp_lo - g * jp_ln + h * jo_kn);
+		T a12 = -(e * kp_lo - g * ip_lm + h * io_km);
+		T a13 = +(e * jp_ln - f * ip_lm + h * in_jm);
+		T a14 = -(e * jo_kn - f * io_km + g * in_jm);
+
+		T det = a * a11 + b * a12 + c * a13 + d * a14;
+
+		// Negated comparison so NaN determinants are reported as singular as well
+		if (!(T.Abs(det) > epsilon))
+		{
+			result = default;
+			return false;
+		}
+
+		T invDet = T.One / det;
+
+		T gp_ho = g * p - h * o;
+		T fp_hn = f * p - h * n;
+		T fo_gn = f * o - g * n;
+		T ep_hm = e * p - h * m;
+		T eo_gm = e * o - g * m;
+		T en_fm = e * n - f * m;
+
+		T gl_hk = g * l - h * k;
+		T fl_hj = f * l - h * j;
+		T fk_gj = f * k - g * j;
+		T el_hi = e * l - h * i;
+		T ek_gi = e * k - g * i;
+		T ej_fi = e * j - f * i;
+
+		result = new(
+			m11: a11 * invDet,
+			m12: -(b * kp_lo - c * jp_ln + d * jo_kn) * invDet,
+			m13: +(b * gp_ho - c * fp_hn + d * fo_gn) * invDet,
+			m14: -(b * gl_hk - c * fl_hj + d * fk_gj) * invDet,
+			m21: a12 * invDet,
+			m22: +(a * kp_lo - c * ip_lm + d * io_km) * invDet,
+			m23: -(a * gp_ho - c * ep_hm + d * eo_gm) * invDet,
+			m24: +(a * gl_hk - c * el_hi + d * ek_gi) * invDet,
+			m31: a13 * invDet,
+			m32: -(a * jp_ln - b * ip_lm + d * in_jm) * invDet,
+			m33: +(a * fp_hn - b * ep_hm + d * en_fm) * invDet,
+			m34: -(a * fl_hj - b * el_hi + d * ej_fi) * invDet,
+			m41: a14 * invDet,
+			m42: +(a * jo_kn - b * io_km + c * in_jm) * invDet,
+			m43: -(a * fo_gn - b * eo_gm + c * en_fm) * invDet,
+			m44: +(a * fk_gj - b * ek_gi + c * ej_fi) * invDet
+			);
+		return true;
+	}
+	private static bool InvertAffine<T>(Mat4<T> matrix, T epsilon, out Mat4<T> result)
+		where T : unmanaged, INumberBase<T>, IComparisonOperators<T, T, bool>
+	{
+		T a = matrix.M11, b = matrix.M12, c = matrix.M13;
+		T e = matrix.M21, f = matrix.M22, g = matrix.M23;
+		T i = matrix.M31, j = matrix.M32, k = matrix.M33;
+
+		T fk_gj = f * k - g * j;
+		T gi_ek = g * i - e * k;
+		T ej_fi = e * j - f * i;
+
+		T det = a * fk_gj + b * gi_ek + c * ej_fi;
+
+		if (!(T.Abs(det) > epsilon))
+		{
+			result = default;
+			return false;
+		}
+
+		T invDet = T.One / det;
+
+		T r11 = fk_gj * invDet;
+		T r12 = (c * j - b * k) * invDet;
+		T r13 = (b * g - c * f) * invDet;
+		T r21 = gi_ek * invDet;
+		T r22 = (a * k - c * i) * invDet;
+		T r23 = (c * e - a * g) * invDet;
+		T r31 = ej_fi * invDet;
+		T r32 = (b * i - a * j) * invDet;
+		T r33 = (a * f - b * e) * invDet;
+
+		T x = matrix.M41, y = matrix.M42, z = matrix.M43;
+
+		result = new(
+			m11: r11,
+			m12: r12,
+			m13: r13,
+			m14: T.Zero,
+			m21: r21,
+			m22: r22,
+			m23: r23,
+			m24: T.Zero,
+			m31: r31,
+			m32: r32,
+			m33: r33,
+			m34: T.Zero,
+			m41: -(x * r11 + y * r21 + z * r31),
+			m42: -(x * r12 + y * r22 + z * r32),
+			m43: -(x * r13 + y * r23 + z * r33),
+			m44: T.One
+			);
+		return true;
+	}
+
+	/// <summary>Approximate counterpart of <see cref="Mat4{T}.IsIdentity" />.</summary>
+	/// <remarks>Most identity elements are zero, so a <see cref="ToleranceMode.Relative" /> tolerance rejects any noise there, prefer <see cref="Tolerance{T}.Combined" /> or <see cref="Tolerance{T}.Absolute" />.</remarks>
+	public static bool IsApproximatelyIdentity<T>(Mat4<T> matrix, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> ApproximatelyEquals(matrix, Mat4<T>.Identity, tolerance);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> Multiply<T>(Mat4<T> left, Mat4<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> Multiply<T>(Mat4<T> left, T right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> Negate<T>(Mat4<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> -operand;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> Subtract<T>(Mat4<T> left, Mat4<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left - right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Trace<T>(Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> matrix.M11 + matrix.M22 + matrix.M33 + matrix.M44;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> Transpose<T>(Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			matrix.M11, matrix.M21, matrix.M31, matrix.M41,
+			matrix.M12, matrix.M22, matrix.M32, matrix.M42,
+			matrix.M13, matrix.M23, matrix.M33, matrix.M43,
+			matrix.M14, matrix.M24, matrix.M34, matrix.M44
+			);
 }
\ No newline at end of file
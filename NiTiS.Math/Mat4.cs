@@ -1,10 +1,31 @@
-﻿using System.Numerics;
+﻿using System;
+using System.Diagnostics;
+using System.Diagnostics.CodeAnalysis;
+using System.Globalization;
+using System.Numerics;
 using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
 
-public readonly unsafe struct Mat4<T>
+[DebuggerDisplay($@"{{{nameof(ToString)}(""G""),nq}}")]
+public readonly unsafe struct Mat4<T> :
+	// Matrix op Matrix
+	IAdditionOperators<Mat4<T>, Mat4<T>, Mat4<T>>,
+	ISubtractionOperators<Mat4<T>, Mat4<T>, Mat4<T>>,
+	IMultiplyOperators<Mat4<T>, Mat4<T>, Mat4<T>>,
+	IEqualityOperators<Mat4<T>, Mat4<T>, bool>,
+	// Matrix op T
+	IMultiplyOperators<Mat4<T>, T, Mat4<T>>,
+	// Unary op
+	IUnaryNegationOperators<Mat4<T>, Mat4<T>>,
+	IUnaryPlusOperators<Mat4<T>, Mat4<T>>,
+	ISpanFormattable,
+#if NET8_0_OR_GREATER
+	IUtf8SpanFormattable,
+#endif
+	IEquatable<Mat4<T>>
 	where T : unmanaged, INumberBase<T>
 {
 	#region Matrix
@@ -63,6 +84,110 @@ public readonly unsafe struct Mat4<T>
 		M44 = m44;
 	}
 
+	/// <summary>Converts every component, throwing <see cref="OverflowException" /> when it is not representable.</summary>
+	public static Mat4<T> CreateChecked<TOther>(Mat4<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(
+			T.CreateChecked(value.M11), T.CreateChecked(value.M12), T.CreateChecked(value.M13), T.CreateChecked(value.M14),
+			T.CreateChecked(value.M21), T.CreateChecked(value.M22), T.CreateChecked(value.M23), T.CreateChecked(value.M24),
+			T.CreateChecked(value.M31), T.CreateChecked(value.M32), T.CreateChecked(value.M33), T.CreateChecked(value.M34),
+			T.CreateChecked(value.M41), T.CreateChecked(value.M42), T.CreateChecked(value.M43), T.CreateChecked(value.M44)
+			);
+	/// <summary>Converts every component, clamping values that are not representable.</summary>
+	public static Mat4<T> CreateSaturating<TOther>(Mat4<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(
+			T.CreateSaturating(value.M11), T.CreateSaturating(value.M12), T.CreateSaturating(value.M13), T.CreateSaturating(value.M14),
+			T.CreateSaturating(value.M21), T.CreateSaturating(value.M22), T.CreateSaturating(value.M23), T.CreateSaturating(value.M24),
+			T.CreateSaturating(value.M31), T.CreateSaturating(value.M32), T.CreateSaturating(value.M33), T.CreateSaturating(value.M34),
+			T.CreateSaturating(value.M41), T.CreateSaturating(value.M42), T.CreateSaturating(value.M43), T.CreateSaturating(value.M44)
+			);
+	/// <summary>Converts every component, truncating values that are not representable.</summary>
+	public static Mat4<T> CreateTruncating<TOther>(Mat4<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(
+			T.CreateTruncating(value.M11), T.CreateTruncating(value.M12), T.CreateTruncating(value.M13), T.CreateTruncating(value.M14),
+			T.CreateTruncating(value.M21), T.CreateTruncating(value.M22), T.CreateTruncating(value.M23), T.CreateTruncating(value.M24),
+			T.CreateTruncating(value.M31), T.CreateTruncating(value.M32), T.CreateTruncating(value.M33), T.CreateTruncating(value.M34),
+			T.CreateTruncating(value.M41), T.CreateTruncating(value.M42), T.CreateTruncating(value.M43), T.CreateTruncating(value.M44)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> operator +(Mat4<T> left, Mat4<T> right)
+		=> new(
+			left.M11 + right.M11, left.M12 + right.M12, left.M13 + right.M13, left.M14 + right.M14,
+			left.M21 + right.M21, left.M22 + right.M22, left.M23 + right.M23, left.M24 + right.M24,
+			left.M31 + right.M31, left.M32 + right.M32, left.M33 + right.M33, left.M34 + right.M34,
+			left.M41 + right.M41, left.M42 + right.M42, left.M43 + right.M43, left.M44 + right.M44
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> operator -(Mat4<T> left, Mat4<T> right)
+		=> new(
+			left.M11 - right.M11, left.M12 - right.M12, left.M13 - right.M13, left.M14 - right.M14,
+			left.M21 - right.M21, left.M22 - right.M22, left.M23 - right.M23, left.M24 - right.M24,
+			left.M31 - right.M31, left.M32 - right.M32, left.M33 - right.M33, left.M34 - right.M34,
+			left.M41 - right.M41, left.M42 - right.M42, left.M43 - right.M43, left.M44 - right.M44
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> operator *(Mat4<T> left, Mat4<T> right)
+		=> Simd.IsAccelerated<T>()
+		? Simd.Multiply(left, right)
+		: new(
+			left.M11 * right.M11 + left.M12 * right.M21 + left.M13 * right.M31 + left.M14 * right.M41,
+			left.M11 * right.M12 + left.M12 * right.M22 + left.M13 * right.M32 + left.M14 * right.M42,
+			left.M11 * right.M13 + left.M12 * right.M23 + left.M13 * right.M33 + left.M14 * right.M43,
+			left.M11 * right.M14 + left.M12 * right.M24 + left.M13 * right.M34 + left.M14 * right.M44,
+
+			left.M21 * right.M11 + left.M22 * right.M21 + left.M23 * right.M31 + left.M24 * right.M41,
+			left.M21 * right.M12 + left.M22 * right.M22 + left.M23 * right.M32 + left.M24 * right.M42,
+			left.M21 * right.M13 + left.M22 * right.M23 + left.M23 * right.M33 + left.M24 * right.M43,
+			left.M21 * right.M14 + left.M22 * right.M24 + left.M23 * right.M34 + left.M24 * right.M44,
+
+			left.M31 * right.M11 + left.M32 * right.M21 + left.M33 * right.M31 + left.M34 * right.M41,
+			left.M31 * right.M12 + left.M32 * right.M22 + left.M33 * right.M32 + left.M34 * right.M42,
+			left.M31 * right.M13 + left.M32 * right.M23 + left.M33 * right.M33 + left.M34 * right.M43,
+			left.M31 * right.M14 + left.M32 * right.M24 + left.M33 * right.M34 + left.M34 * right.M44,
+
+			left.M41 * right.M11 + left.M42 * right.M21 + left.M43 * right.M31 + left.M44 * right.M41,
+			left.M41 * right.M12 + left.M42 * right.M22 + left.M43 * right.M32 + left.M44 * right.M42,
+			left.M41 * right.M13 + left.M42 * right.M23 + left.M43 * right.M33 + left.M44 * right.M43,
+			left.M41 * right.M14 + left.M42 * right.M24 + left.M43 * right.M34 + left.M44 * right.M44
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(Mat4<T> left, Mat4<T> right)
+		=> left.M11 == right.M11 && left.M12 == right.M12 && left.M13 == right.M13 && left.M14 == right.M14
+		&& left.M21 == right.M21 && left.M22 == right.M22 && left.M23 == right.M23 && left.M24 == right.M24
+		&& left.M31 == right.M31 && left.M32 == right.M32 && left.M33 == right.M33 && left.M34 == right.M34
+		&& left.M41 == right.M41 && left.M42 == right.M42 && left.M43 == right.M43 && left.M44 == right.M44;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(Mat4<T> left, Mat4<T> right)
+		=> !(left == right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> operator *(Mat4<T> left, T right)
+		=> new(
+			left.M11 * right, left.M12 * right, left.M13 * right, left.M14 * right,
+			left.M21 * right, left.M22 * right, left.M23 * right, left.M24 * right,
+			left.M31 * right, left.M32 * right, left.M33 * right, left.M34 * right,
+			left.M41 * right, left.M42 * right, left.M43 * right, left.M44 * right
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> operator *(T left, Mat4<T> right)
+		=> right * left;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> operator -(Mat4<T> operand)
+		=> new(
+			-operand.M11, -operand.M12, -operand.M13, -operand.M14,
+			-operand.M21, -operand.M22, -operand.M23, -operand.M24,
+			-operand.M31, -operand.M32, -operand.M33, -operand.M34,
+			-operand.M41, -operand.M42, -operand.M43, -operand.M44
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Mat4<T> operator +(Mat4<T> operand)
+		=> operand;
+
 	[MethodImpl(AggressiveOptimization | AggressiveInlining)]
 	public static Mat4<float> ConvertFromSystem(Matrix4x4 matrix)
 		=> *((Mat4<float>*)&matrix);
@@ -85,4 +210,35 @@ public readonly unsafe struct Mat4<T>
 				M41 == T.Zero && M42 == T.Zero && M43 == T.Zero;
 		}
 	}
+
+	/// <summary>Indicates whether the current matrix is affine, i.e. its last column is (0, 0, 0, 1).</summary>
+	public readonly bool IsAffine
+		=> M14 == T.Zero && M24 == T.Zero && M34 == T.Zero && M44 == T.One;
+
+	public override readonly int GetHashCode()
+	{
+		HashCode hash = new();
+		hash.Add(M11); hash.Add(M12); hash.Add(M13); hash.Add(M14);
+		hash.Add(M21); hash.Add(M22); hash.Add(M23); hash.Add(M24);
+		hash.Add(M31); hash.Add(M32); hash.Add(M33); hash.Add(M34);
+		hash.Add(M41); hash.Add(M42); hash.Add(M43); hash.Add(M44);
+		return hash.ToHashCode();
+	}
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Mat4<T> mat
+		? mat == this : false;
+	public readonly bool Equals(Mat4<T> other)
+		=> this == other;
+
+	public override readonly string ToString() => ToString("G", CultureInfo.CurrentCulture);
+	public readonly string ToString(string? format) => ToString(format, CultureInfo.CurrentCulture);
+	/// <summary>Formats the matrix as its rows, <c>[&lt;m11, m12, m13, m14&gt; ... &lt;m41, m42, m43, m44&gt;]</c>.</summary>
+	public readonly string ToString(string? format, IFormatProvider? formatProvider)
+		=> Formatting.ToString(this, format, formatProvider);
+	public readonly bool TryFormat(Span<char> destination, out int charsWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatRows(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in M11), 16), 4, destination, out charsWritten, format, provider);
+#if NET8_0_OR_GREATER
+	public readonly bool TryFormat(Span<byte> utf8Destination, out int bytesWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatRows(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in M11), 16), 4, utf8Destination, out bytesWritten, format, provider);
+#endif
 }
\ No newline at end of file
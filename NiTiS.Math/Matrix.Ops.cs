@@ -0,0 +1,127 @@
+﻿using NiTiS.Math.LinearAlgebra;
+using System;
+using System.Diagnostics.CodeAnalysis;
+using System.Numerics;
+
+namespace NiTiS.Math;
+
+public static class Matrix
+{
+	public static Matrix<T> Add<T>(Matrix<T> left, Matrix<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left + right;
+
+	public static CholeskyDecomposition<T> DecomposeCholesky<T>(Matrix<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(matrix);
+
+	public static LUDecomposition<T> DecomposeLU<T>(Matrix<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(matrix);
+
+	public static QRDecomposition<T> DecomposeQR<T>(Matrix<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(matrix);
+
+	/// <summary>Computes the singular value decomposition of the given matrix.</summary>
+	public static SingularValueDecomposition<T> DecomposeSvd<T>(Matrix<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(matrix);
+
+	/// <summary>Computes the eigen decomposition of the given symmetric matrix, only its lower triangle is read.</summary>
+	public static SymmetricEigenDecomposition<T> DecomposeSymmetricEigen<T>(Matrix<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(matrix);
+
+	public static T Determinant<T>(Matrix<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> DecomposeLU(matrix).Determinant;
+
+	/// <summary>Attempts to invert the given square matrix.</summary>
+	/// <returns><see langword="true" /> if the matrix was inverted; otherwise, <see langword="false" />.</returns>
+	public static bool Invert<T>(Matrix<T> matrix, [NotNullWhen(true)] out Matrix<T>? result)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		LUDecomposition<T> lu = DecomposeLU(matrix);
+
+		if (lu.IsSingular)
+		{
+			result = null;
+			return false;
+		}
+
+		result = lu.Solve(Matrix<T>.CreateIdentity(matrix.Rows));
+		return true;
+	}
+
+	public static Matrix<T> Multiply<T>(Matrix<T> left, Matrix<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+	public static Matrix<T> Multiply<T>(Matrix<T> left, T right)
+		where T : unmanaged, INumberBase<T>
+		=> left * right;
+	/// <summary>Multiplies the matrix by a column vector.</summary>
+	public static T[] Multiply<T>(Matrix<T> left, ReadOnlySpan<T> right)
+		where T : unmanaged, INumberBase<T>
+	{
+		if (right.Length != left.Columns)
+			throw new ArgumentException("Vector length must be equal to matrix columns", nameof(right));
+
+		T[] result = new T[left.Rows];
+
+		for (int i = 0; i < result.Length; i++)
+		{
+			ReadOnlySpan<T> row = left.GetRow(i);
+
+			T sum = T.Zero;
+			for (int j = 0; j < row.Length; j++)
+				sum += row[j] * right[j];
+
+			result[i] = sum;
+		}
+
+		return result;
+	}
+
+	public static Matrix<T> Negate<T>(Matrix<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> -operand;
+
+	/// <summary>
+	/// Solves <c>A * x = b</c>. Square matrices are solved with LU decomposition,
+	/// matrices with more rows than columns are solved in the least squares sense with QR decomposition.
+	/// </summary>
+	public static T[] Solve<T>(Matrix<T> matrix, ReadOnlySpan<T> vector)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> matrix.IsSquare
+		? DecomposeLU(matrix).Solve(vector)
+		: DecomposeQR(matrix).Solve(vector);
+	/// <summary>
+	/// Solves <c>A * X = B</c>. Square matrices are solved with LU decomposition,
+	/// matrices with more rows than columns are solved in the least squares sense with QR decomposition.
+	/// </summary>
+	public static Matrix<T> Solve<T>(Matrix<T> matrix, Matrix<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> matrix.IsSquare
+		? DecomposeLU(matrix).Solve(right)
+		: DecomposeQR(matrix).Solve(right);
+
+	public static Matrix<T> Subtract<T>(Matrix<T> left, Matrix<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left - right;
+
+	public static Matrix<T> Transpose<T>(Matrix<T> matrix)
+		where T : unmanaged, INumberBase<T>
+	{
+		Matrix<T> result = new(matrix.Columns, matrix.Rows);
+
+		for (int i = 0; i < matrix.Rows; i++)
+		{
+			ReadOnlySpan<T> row = matrix.GetRow(i);
+			for (int j = 0; j < row.Length; j++)
+				result[j, i] = row[j];
+		}
+
+		return result;
+	}
+}
@@ -0,0 +1,186 @@
+﻿using System;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Heap-backed matrix of arbitrary size, stored row-major.
+/// </summary>
+public sealed class Matrix<T> :
+	// Matrix op Matrix
+	IAdditionOperators<Matrix<T>, Matrix<T>, Matrix<T>>,
+	ISubtractionOperators<Matrix<T>, Matrix<T>, Matrix<T>>,
+	IMultiplyOperators<Matrix<T>, Matrix<T>, Matrix<T>>,
+	// Matrix op T
+	IMultiplyOperators<Matrix<T>, T, Matrix<T>>,
+	// Unary op
+	IUnaryNegationOperators<Matrix<T>, Matrix<T>>,
+	IUnaryPlusOperators<Matrix<T>, Matrix<T>>
+	where T : unmanaged, INumberBase<T>
+{
+	private readonly T[] data;
+
+	public int Rows { get; }
+	public int Columns { get; }
+	public bool IsSquare => Rows == Columns;
+
+	public Matrix(int rows, int columns)
+	{
+		if (rows < 0)
+			throw new ArgumentOutOfRangeException(nameof(rows));
+		if (columns < 0)
+			throw new ArgumentOutOfRangeException(nameof(columns));
+
+		Rows = rows;
+		Columns = columns;
+		data = new T[rows * columns];
+	}
+	public Matrix(int rows, int columns, ReadOnlySpan<T> data)
+		: this(rows, columns)
+	{
+		if (data.Length != rows * columns)
+			throw new ArgumentException("Data length must be equal to rows * columns", nameof(data));
+
+		data.CopyTo(this.data);
+	}
+
+	public T this[int row, int column]
+	{
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		get => data[GetIndex(row, column)];
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		set => data[GetIndex(row, column)] = value;
+	}
+
+	public static Matrix<T> CreateIdentity(int size)
+	{
+		Matrix<T> result = new(size, size);
+
+		for (int i = 0; i < size; i++)
+			result.data[i * size + i] = T.One;
+
+		return result;
+	}
+
+	/// <summary>Returns the underlying row-major storage.</summary>
+	public Span<T> AsSpan()
+		=> data;
+	public Span<T> GetRow(int row)
+	{
+		if ((uint)row >= (uint)Rows)
+			throw new ArgumentOutOfRangeException(nameof(row));
+
+		return data.AsSpan(row * Columns, Columns);
+	}
+	public T[] GetColumn(int column)
+	{
+		if ((uint)column >= (uint)Columns)
+			throw new ArgumentOutOfRangeException(nameof(column));
+
+		T[] result = new T[Rows];
+		for (int i = 0; i < Rows; i++)
+			result[i] = data[i * Columns + column];
+
+		return result;
+	}
+	public Matrix<T> Clone()
+		=> new(Rows, Columns, data);
+	/// <summary>Copies a rectangular block of this matrix into a new matrix.</summary>
+	public Matrix<T> Slice(int row, int column, int rows, int columns)
+	{
+		if (row < 0 || rows < 0 || row + rows > Rows)
+			throw new ArgumentOutOfRangeException(nameof(rows));
+		if (column < 0 || columns < 0 || column + columns > Columns)
+			throw new ArgumentOutOfRangeException(nameof(columns));
+
+		Matrix<T> result = new(rows, columns);
+
+		for (int i = 0; i < rows; i++)
+			data.AsSpan((row + i) * Columns + column, columns).CopyTo(result.data.AsSpan(i * columns, columns));
+
+		return result;
+	}
+
+	public static Matrix<T> operator +(Matrix<T> left, Matrix<T> right)
+	{
+		ThrowIfSizeMismatch(left, right);
+
+		Matrix<T> result = new(left.Rows, left.Columns);
+		for (int i = 0; i < result.data.Length; i++)
+			result.data[i] = left.data[i] + right.data[i];
+
+		return result;
+	}
+	public static Matrix<T> operator -(Matrix<T> left, Matrix<T> right)
+	{
+		ThrowIfSizeMismatch(left, right);
+
+		Matrix<T> result = new(left.Rows, left.Columns);
+		for (int i = 0; i < result.data.Length; i++)
+			result.data[i] = left.data[i] - right.data[i];
+
+		return result;
+	}
+	public static Matrix<T> operator *(Matrix<T> left, Matrix<T> right)
+	{
+		if (left.Columns != right.Rows)
+			throw new ArgumentException("Left matrix columns must be equal to right matrix rows", nameof(right));
+
+		Matrix<T> result = new(left.Rows, right.Columns);
+
+		for (int i = 0; i < left.Rows; i++)
+		{
+			ReadOnlySpan<T> leftRow = left.data.AsSpan(i * left.Columns, left.Columns);
+			Span<T> resultRow = result.data.AsSpan(i * right.Columns, right.Columns);
+
+			for (int k = 0; k < leftRow.Length; k++)
+			{
+				T factor = leftRow[k];
+				ReadOnlySpan<T> rightRow = right.data.AsSpan(k * right.Columns, right.Columns);
+
+				for (int j = 0; j < resultRow.Length; j++)
+					resultRow[j] += factor * rightRow[j];
+			}
+		}
+
+		return result;
+	}
+	public static Matrix<T> operator *(Matrix<T> left, T right)
+	{
+		Matrix<T> result = new(left.Rows, left.Columns);
+		for (int i = 0; i < result.data.Length; i++)
+			result.data[i] = left.data[i] * right;
+
+		return result;
+	}
+	public static Matrix<T> operator *(T left, Matrix<T> right)
+		=> right * left;
+	public static Matrix<T> operator -(Matrix<T> operand)
+	{
+		Matrix<T> result = new(operand.Rows, operand.Columns);
+		for (int i = 0; i < result.data.Length; i++)
+			result.data[i] = -operand.data[i];
+
+		return result;
+	}
+	public static Matrix<T> operator +(Matrix<T> operand)
+		=> operand.Clone();
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	private int GetIndex(int row, int column)
+	{
+		if ((uint)row >= (uint)Rows)
+			throw new ArgumentOutOfRangeException(nameof(row));
+		if ((uint)column >= (uint)Columns)
+			throw new ArgumentOutOfRangeException(nameof(column));
+
+		return row * Columns + column;
+	}
+	private static void ThrowIfSizeMismatch(Matrix<T> left, Matrix<T> right)
+	{
+		if (left.Rows != right.Rows || left.Columns != right.Columns)
+			throw new ArgumentException("Matrices must have the same size", nameof(right));
+	}
+}
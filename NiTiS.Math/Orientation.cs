@@ -0,0 +1,14 @@
+﻿namespace NiTiS.Math;
+
+/// <summary>
+/// Describes the turn made by three points in the plane.
+/// </summary>
+public enum Orientation
+{
+	/// <summary>The points turn clockwise.</summary>
+	Clockwise = -1,
+	/// <summary>The points lie on one line.</summary>
+	Collinear = 0,
+	/// <summary>The points turn counterclockwise.</summary>
+	CounterClockwise = 1,
+}
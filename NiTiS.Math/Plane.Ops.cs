@@ -0,0 +1,38 @@
+﻿using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class Plane
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Dot<T>(Plane<T> plane, Vector4D<T> value)
+		where T : unmanaged, INumberBase<T>
+		=> plane.Normal.X * value.X + plane.Normal.Y * value.Y + plane.Normal.Z * value.Z + plane.D * value.W;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T DotCoordinate<T>(Plane<T> plane, Vector3D<T> value)
+		where T : unmanaged, INumberBase<T>
+		=> Vector3D.Dot(plane.Normal, value) + plane.D;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T DotNormal<T>(Plane<T> plane, Vector3D<T> value)
+		where T : unmanaged, INumberBase<T>
+		=> Vector3D.Dot(plane.Normal, value);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Plane<T> Normalize<T>(Plane<T> plane)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		// Smallest value such that 1 + epsilon != 1
+		T epsilon = T.BitIncrement(T.One) - T.One;
+
+		T lengthSquared = plane.Normal.LengthSquared;
+		if (T.Abs(lengthSquared - T.One) < epsilon)
+			return plane;
+
+		T length = T.Sqrt(lengthSquared);
+		return new(plane.Normal / length, plane.D / length);
+	}
+}
@@ -0,0 +1,40 @@
+﻿using System;
+using System.Diagnostics.CodeAnalysis;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public readonly struct Plane<T> :
+	IEquatable<Plane<T>>,
+	IEqualityOperators<Plane<T>, Plane<T>, bool>
+	where T : unmanaged, INumberBase<T>
+{
+	public readonly Vector3D<T> Normal;
+	public readonly T D;
+
+	public Plane(Vector3D<T> normal, T d)
+		=> (Normal, D) = (normal, d);
+	public Plane(T x, T y, T z, T d)
+		=> (Normal, D) = (new(x, y, z), d);
+	public Plane(Vector4D<T> value)
+		=> (Normal, D) = (new(value.X, value.Y, value.Z), value.W);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(Plane<T> left, Plane<T> right)
+		=> left.Normal == right.Normal
+		&& left.D == right.D;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(Plane<T> left, Plane<T> right)
+		=> left.Normal != right.Normal
+		|| left.D != right.D;
+
+	public override readonly int GetHashCode()
+		=> HashCode.Combine(Normal, D);
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Plane<T> plane
+		? plane == this : false;
+	public readonly bool Equals(Plane<T> other)
+		=> this == other;
+}
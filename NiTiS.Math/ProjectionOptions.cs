@@ -0,0 +1,19 @@
+﻿using System;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Clip space conventions used by view and projection matrix factories.
+/// <see cref="None" /> matches <see cref="System.Numerics.Matrix4x4" />: right-handed with depth in [0, 1].
+/// </summary>
+[Flags]
+public enum ProjectionOptions
+{
+	None = 0,
+	/// <summary>View space looks down +Z instead of -Z.</summary>
+	LeftHanded = 1 << 0,
+	/// <summary>Depth is mapped to [-1, 1] (OpenGL) instead of [0, 1] (Direct3D, Vulkan).</summary>
+	NegativeOneToOneDepth = 1 << 1,
+	/// <summary>Near plane is mapped to the far end of the depth range and vice versa.</summary>
+	ReversedZ = 1 << 2,
+}
@@ -0,0 +1,224 @@
+﻿using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class Quaternion
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Add<T>(Quaternion<T> left, Quaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left + right;
+
+	/// <summary>Compares the components, <c>q</c> and <c>-q</c> describe the same rotation but are not considered equal.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool ApproximatelyEquals<T>(Quaternion<T> left, Quaternion<T> right, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> tolerance.AreEqual(left.X, right.X)
+		&& tolerance.AreEqual(left.Y, right.Y)
+		&& tolerance.AreEqual(left.Z, right.Z)
+		&& tolerance.AreEqual(left.W, right.W);
+
+	/// <summary>Concatenates two rotations, the result rotates by <paramref name="first" /> and then by <paramref name="second" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Concatenate<T>(Quaternion<T> first, Quaternion<T> second)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> second * first;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Conjugate<T>(Quaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(-value.X, -value.Y, -value.Z, value.W);
+
+	/// <summary>Creates a quaternion that rotates around <paramref name="axis" /> by <paramref name="angle" />.</summary>
+	/// <param name="axis">Normalized rotation axis.</param>
+	public static Quaternion<T> CreateFromAxisAngle<T>(Vector3D<T> axis, Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T half = angle.Radians / Scalar<T>.Two;
+		T s = T.Sin(half);
+
+		return new(axis.X * s, axis.Y * s, axis.Z * s, T.Cos(half));
+	}
+
+	/// <summary>Creates a quaternion from the rotation part of the given matrix.</summary>
+	public static Quaternion<T> CreateFromRotationMatrix<T>(Mat4<T> matrix)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T half = T.One / Scalar<T>.Two;
+		T trace = matrix.M11 + matrix.M22 + matrix.M33;
+
+		if (trace > T.Zero)
+		{
+			T s = T.Sqrt(trace + T.One);
+			T invS = half / s;
+
+			return new(
+				(matrix.M23 - matrix.M32) * invS,
+				(matrix.M31 - matrix.M13) * invS,
+				(matrix.M12 - matrix.M21) * invS,
+				s * half
+				);
+		}
+
+		if (matrix.M11 >= matrix.M22 && matrix.M11 >= matrix.M33)
+		{
+			T s = T.Sqrt(T.One + matrix.M11 - matrix.M22 - matrix.M33);
+			T invS = half / s;
+
+			return new(
+				half * s,
+				(matrix.M12 + matrix.M21) * invS,
+				(matrix.M13 + matrix.M31) * invS,
+				(matrix.M23 - matrix.M32) * invS
+				);
+		}
+
+		if (matrix.M22 > matrix.M33)
+		{
+			T s = T.Sqrt(T.One + matrix.M22 - matrix.M11 - matrix.M33);
+			T invS = half / s;
+
+			return new(
+				(matrix.M21 + matrix.M12) * invS,
+				half * s,
+				(matrix.M32 + matrix.M23) * invS,
+				(matrix.M31 - matrix.M13) * invS
+				);
+		}
+		else
+		{
+			T s = T.Sqrt(T.One + matrix.M33 - matrix.M11 - matrix.M22);
+			T invS = half / s;
+
+			return new(
+				(matrix.M31 + matrix.M13) * invS,
+				(matrix.M32 + matrix.M23) * invS,
+				half * s,
+				(matrix.M12 - matrix.M21) * invS
+				);
+		}
+	}
+
+	/// <summary>Creates a quaternion from yaw around the Y axis, pitch around the X axis and roll around the Z axis, applied in that order.</summary>
+	public static Quaternion<T> CreateFromYawPitchRoll<T>(Angle<T> yaw, Angle<T> pitch, Angle<T> roll)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T half = T.One / Scalar<T>.Two;
+
+		T sr = T.Sin(roll.Radians * half), cr = T.Cos(roll.Radians * half);
+		T sp = T.Sin(pitch.Radians * half), cp = T.Cos(pitch.Radians * half);
+		T sy = T.Sin(yaw.Radians * half), cy = T.Cos(yaw.Radians * half);
+
+		return new(
+			cy * sp * cr + sy * cp * sr,
+			sy * cp * cr - cy * sp * sr,
+			cy * cp * sr - sy * sp * cr,
+			cy * cp * cr + sy * sp * sr
+			);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Divide<T>(Quaternion<T> left, Quaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left / right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Dot<T>(Quaternion<T> left, Quaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left.X * right.X + left.Y * right.Y + left.Z * right.Z + left.W * right.W;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Inverse<T>(Quaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Conjugate(value) * (T.One / LengthSquared(value));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Length<T>(Quaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> T.Sqrt(LengthSquared(value));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T LengthSquared<T>(Quaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Dot(value, value);
+
+	/// <summary>Linearly interpolates each component, the result is not normalized.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Lerp<T>(Quaternion<T> left, Quaternion<T> right, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left * (T.One - amount) + right * amount;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Multiply<T>(Quaternion<T> left, Quaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left * right;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Multiply<T>(Quaternion<T> left, T right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left * right;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Negate<T>(Quaternion<T> operand)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> -operand;
+
+	/// <summary>Linearly interpolates along the shortest path and normalizes the result.</summary>
+	public static Quaternion<T> Nlerp<T>(Quaternion<T> left, Quaternion<T> right, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		if (Dot(left, right) < T.Zero)
+			right = -right;
+
+		return Normalize(Lerp(left, right, amount));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Normalize<T>(Quaternion<T> value)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> value * (T.One / Length(value));
+
+	/// <summary>Spherically interpolates along the shortest path.</summary>
+	public static Quaternion<T> Slerp<T>(Quaternion<T> left, Quaternion<T> right, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T epsilon = T.CreateTruncating(1e-6);
+
+		T cosOmega = Dot(left, right);
+		bool flip = false;
+
+		if (cosOmega < T.Zero)
+		{
+			flip = true;
+			cosOmega = -cosOmega;
+		}
+
+		T s1, s2;
+
+		if (cosOmega > T.One - epsilon)
+		{
+			// Too close for a stable division, fall back to linear interpolation
+			s1 = T.One - amount;
+			s2 = amount;
+		}
+		else
+		{
+			T omega = T.Acos(cosOmega);
+			T invSinOmega = T.One / T.Sin(omega);
+
+			s1 = T.Sin((T.One - amount) * omega) * invSinOmega;
+			s2 = T.Sin(amount * omega) * invSinOmega;
+		}
+
+		if (flip)
+			s2 = -s2;
+
+		return left * s1 + right * s2;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> Subtract<T>(Quaternion<T> left, Quaternion<T> right)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> left - right;
+}
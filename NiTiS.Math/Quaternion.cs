@@ -0,0 +1,132 @@
+﻿using System;
+using System.Diagnostics;
+using System.Diagnostics.CodeAnalysis;
+using System.Globalization;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Rotation quaternion, layout-compatible with <see cref="System.Numerics.Quaternion" /> for <see langword="float" />.
+/// </summary>
+/// <remarks>
+/// Multiplication is the Hamilton product, so <c>left * right</c> rotates by <c>right</c> first and by <c>left</c> second.
+/// </remarks>
+[DebuggerDisplay($@"{{{nameof(ToString)}(""G""),nq}}")]
+public readonly unsafe struct Quaternion<T> :
+	// Quaternion op Quaternion
+	IAdditionOperators<Quaternion<T>, Quaternion<T>, Quaternion<T>>,
+	ISubtractionOperators<Quaternion<T>, Quaternion<T>, Quaternion<T>>,
+	IMultiplyOperators<Quaternion<T>, Quaternion<T>, Quaternion<T>>,
+	IDivisionOperators<Quaternion<T>, Quaternion<T>, Quaternion<T>>,
+	IEqualityOperators<Quaternion<T>, Quaternion<T>, bool>,
+	// Quaternion op T
+	IMultiplyOperators<Quaternion<T>, T, Quaternion<T>>,
+	// Unary op
+	IUnaryNegationOperators<Quaternion<T>, Quaternion<T>>,
+	IUnaryPlusOperators<Quaternion<T>, Quaternion<T>>,
+	IFormattable,
+	IEquatable<Quaternion<T>>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	public readonly T X;
+	public readonly T Y;
+	public readonly T Z;
+	public readonly T W;
+
+	public Quaternion(T x, T y, T z, T w)
+		=> (X, Y, Z, W) = (x, y, z, w);
+	public Quaternion(Vector3D<T> vectorPart, T scalarPart)
+		=> (X, Y, Z, W) = (vectorPart.X, vectorPart.Y, vectorPart.Z, scalarPart);
+	public static Quaternion<T> Identity => new(T.Zero, T.Zero, T.Zero, T.One);
+	public static Quaternion<T> Zero => new(T.Zero, T.Zero, T.Zero, T.Zero);
+
+	/// <summary>Imaginary part of the quaternion.</summary>
+	public Vector3D<T> VectorPart => new(X, Y, Z);
+	/// <summary>Indicates whether the current quaternion is the identity quaternion.</summary>
+	public readonly bool IsIdentity
+		=> X == T.Zero && Y == T.Zero && Z == T.Zero && W == T.One;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator +(Quaternion<T> left, Quaternion<T> right)
+		=> new(
+			left.X + right.X,
+			left.Y + right.Y,
+			left.Z + right.Z,
+			left.W + right.W
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator -(Quaternion<T> left, Quaternion<T> right)
+		=> new(
+			left.X - right.X,
+			left.Y - right.Y,
+			left.Z - right.Z,
+			left.W - right.W
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator *(Quaternion<T> left, Quaternion<T> right)
+		=> new(
+			left.W * right.X + left.X * right.W + left.Y * right.Z - left.Z * right.Y,
+			left.W * right.Y + left.Y * right.W + left.Z * right.X - left.X * right.Z,
+			left.W * right.Z + left.Z * right.W + left.X * right.Y - left.Y * right.X,
+			left.W * right.W - left.X * right.X - left.Y * right.Y - left.Z * right.Z
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator /(Quaternion<T> left, Quaternion<T> right)
+		=> left * Quaternion.Inverse(right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator ==(Quaternion<T> left, Quaternion<T> right)
+		=> left.X == right.X
+		&& left.Y == right.Y
+		&& left.Z == right.Z
+		&& left.W == right.W;
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool operator !=(Quaternion<T> left, Quaternion<T> right)
+		=> !(left == right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator *(Quaternion<T> left, T right)
+		=> new(
+			left.X * right,
+			left.Y * right,
+			left.Z * right,
+			left.W * right
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator *(T left, Quaternion<T> right)
+		=> right * left;
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator -(Quaternion<T> operand)
+		=> new(
+			-operand.X,
+			-operand.Y,
+			-operand.Z,
+			-operand.W
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Quaternion<T> operator +(Quaternion<T> operand)
+		=> operand;
+
+	[MethodImpl(AggressiveOptimization | AggressiveInlining)]
+	public static Quaternion<float> ConvertFromSystem(System.Numerics.Quaternion quaternion)
+		=> *((Quaternion<float>*)&quaternion);
+	[MethodImpl(AggressiveOptimization | AggressiveInlining)]
+	public static System.Numerics.Quaternion ConvertToSystem(Quaternion<float> quaternion)
+		=> *((System.Numerics.Quaternion*)&quaternion);
+
+	public override readonly int GetHashCode()
+		=> HashCode.Combine(X, Y, Z, W);
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Quaternion<T> quat
+		? quat == this : false;
+	public readonly bool Equals(Quaternion<T> other)
+		=> this == other;
+	public override readonly string ToString() => ToString("G", CultureInfo.CurrentCulture);
+	public readonly string ToString(string? format) => ToString(format, CultureInfo.CurrentCulture);
+	public readonly string ToString(string? format, IFormatProvider? formatProvider)
+		=> new Vector4D<T>(X, Y, Z, W).ToString(format, formatProvider);
+}
@@ -12,4 +12,37 @@ internal static class Scalar<T>
 		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 		get => T.One + T.One;
 	}
+}
+
+internal static class Scalar
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Fract<T>(T value)
+		where T : IFloatingPoint<T>
+		=> value - T.Floor(value);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Mod<T>(T left, T right)
+		where T : IFloatingPoint<T>
+		=> left - right * T.Floor(left / right);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Sign<T>(T value)
+		where T : INumber<T>
+		=> T.IsNaN(value)
+		? value
+		: T.CreateTruncating(T.Sign(value));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T SmoothStep<T>(T edge0, T edge1, T value)
+		where T : IFloatingPoint<T>
+	{
+		T t = T.Clamp((value - edge0) / (edge1 - edge0), T.Zero, T.One);
+		return t * t * (T.CreateTruncating(3) - Scalar<T>.Two * t);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Step<T>(T edge, T value)
+		where T : INumber<T>
+		=> value < edge ? T.Zero : T.One;
 }
\ No newline at end of file
@@ -0,0 +1,186 @@
+﻿using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Runtime.Intrinsics;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Hardware accelerated kernels for <see langword="float" /> (<see cref="Vector128{T}" />) and <see langword="double" /> (<see cref="Vector256{T}" />) components.
+/// Callers must check <see cref="IsAccelerated{T}" /> first, three component vectors are widened with a zero W.
+/// </summary>
+internal static class Simd
+{
+	[MethodImpl(AggressiveInlining)]
+	public static bool IsAccelerated<T>()
+		=> (typeof(T) == typeof(float) && Vector128.IsHardwareAccelerated)
+		|| (typeof(T) == typeof(double) && Vector256.IsHardwareAccelerated);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Dot<T>(Vector3D<T> left, Vector3D<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> Dot(Widen(left), Widen(right));
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T Dot<T>(Vector4D<T> left, Vector4D<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> typeof(T) == typeof(float)
+		? As<float, T>(Vector128.Dot(Load128(left), Load128(right)))
+		: As<double, T>(Vector256.Dot(Load256(left), Load256(right)));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Lerp<T>(Vector3D<T> left, Vector3D<T> right, T amount)
+		where T : unmanaged, INumberBase<T>
+		=> Lerp(Widen(left), Widen(right), amount);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Lerp<T>(Vector4D<T> left, Vector4D<T> right, T amount)
+		where T : unmanaged, INumberBase<T>
+	{
+		if (typeof(T) == typeof(float))
+		{
+			Vector128<float> t = Vector128.Create(As<T, float>(amount));
+			return Store<T>(Load128(left) * (Vector128<float>.One - t) + Load128(right) * t);
+		}
+		else
+		{
+			Vector256<double> t = Vector256.Create(As<T, double>(amount));
+			return Store<T>(Load256(left) * (Vector256<double>.One - t) + Load256(right) * t);
+		}
+	}
+
+	public static Mat4<T> Multiply<T>(Mat4<T> left, Mat4<T> right)
+		where T : unmanaged, INumberBase<T>
+	{
+		Mat4<T> result = default;
+
+		ref byte l = ref Unsafe.As<Mat4<T>, byte>(ref left);
+		ref byte r = ref Unsafe.As<Mat4<T>, byte>(ref right);
+		ref byte d = ref Unsafe.As<Mat4<T>, byte>(ref result);
+
+		// Each result row is the left row weighting the rows of the right matrix
+		if (typeof(T) == typeof(float))
+		{
+			Vector128<float> r1 = Unsafe.ReadUnaligned<Vector128<float>>(ref r);
+			Vector128<float> r2 = Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.Add(ref r, 16));
+			Vector128<float> r3 = Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.Add(ref r, 32));
+			Vector128<float> r4 = Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.Add(ref r, 48));
+
+			for (int offset = 0; offset < 64; offset += 16)
+			{
+				Vector128<float> row = Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.Add(ref l, offset));
+
+				Vector128<float> value = r1 * row.GetElement(0)
+					+ r2 * row.GetElement(1)
+					+ r3 * row.GetElement(2)
+					+ r4 * row.GetElement(3);
+
+				Unsafe.WriteUnaligned(ref Unsafe.Add(ref d, offset), value);
+			}
+		}
+		else
+		{
+			Vector256<double> r1 = Unsafe.ReadUnaligned<Vector256<double>>(ref r);
+			Vector256<double> r2 = Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.Add(ref r, 32));
+			Vector256<double> r3 = Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.Add(ref r, 64));
+			Vector256<double> r4 = Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.Add(ref r, 96));
+
+			for (int offset = 0; offset < 128; offset += 32)
+			{
+				Vector256<double> row = Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.Add(ref l, offset));
+
+				Vector256<double> value = r1 * row.GetElement(0)
+					+ r2 * row.GetElement(1)
+					+ r3 * row.GetElement(2)
+					+ r4 * row.GetElement(3);
+
+				Unsafe.WriteUnaligned(ref Unsafe.Add(ref d, offset), value);
+			}
+		}
+
+		return result;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Normalize<T>(Vector3D<T> value)
+		where T : unmanaged, INumberBase<T>
+		=> Normalize(Widen(value));
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Normalize<T>(Vector4D<T> value)
+		where T : unmanaged, INumberBase<T>
+	{
+		if (typeof(T) == typeof(float))
+		{
+			Vector128<float> v = Load128(value);
+			return Store<T>(v / Vector128.Sqrt(Vector128.Create(Vector128.Dot(v, v))));
+		}
+		else
+		{
+			Vector256<double> v = Load256(value);
+			return Store<T>(v / Vector256.Sqrt(Vector256.Create(Vector256.Dot(v, v))));
+		}
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Transform<T>(Vector3D<T> position, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> Transform(position, matrix, true);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> TransformNormal<T>(Vector3D<T> normal, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> Transform(normal, matrix, false);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	private static Vector3D<T> Transform<T>(Vector3D<T> value, Mat4<T> matrix, bool translate)
+		where T : unmanaged, INumberBase<T>
+	{
+		ref byte m = ref Unsafe.As<Mat4<T>, byte>(ref matrix);
+
+		// Row-vector convention, the result is the components weighting the matrix rows
+		if (typeof(T) == typeof(float))
+		{
+			Vector128<float> result = Unsafe.ReadUnaligned<Vector128<float>>(ref m) * As<T, float>(value.X)
+				+ Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.Add(ref m, 16)) * As<T, float>(value.Y)
+				+ Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.Add(ref m, 32)) * As<T, float>(value.Z);
+
+			if (translate)
+				result += Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.Add(ref m, 48));
+
+			return Store<T>(result);
+		}
+		else
+		{
+			Vector256<double> result = Unsafe.ReadUnaligned<Vector256<double>>(ref m) * As<T, double>(value.X)
+				+ Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.Add(ref m, 32)) * As<T, double>(value.Y)
+				+ Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.Add(ref m, 64)) * As<T, double>(value.Z);
+
+			if (translate)
+				result += Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.Add(ref m, 96));
+
+			return Store<T>(result);
+		}
+	}
+
+	[MethodImpl(AggressiveInlining)]
+	private static TTo As<TFrom, TTo>(TFrom value)
+		=> Unsafe.As<TFrom, TTo>(ref value);
+	[MethodImpl(AggressiveInlining)]
+	private static Vector4D<T> Widen<T>(Vector3D<T> value)
+		where T : unmanaged, INumberBase<T>
+		=> new(value, T.Zero);
+	[MethodImpl(AggressiveInlining)]
+	private static Vector128<float> Load128<T>(Vector4D<T> value)
+		where T : unmanaged, INumberBase<T>
+		=> Unsafe.ReadUnaligned<Vector128<float>>(ref Unsafe.As<Vector4D<T>, byte>(ref value));
+	[MethodImpl(AggressiveInlining)]
+	private static Vector256<double> Load256<T>(Vector4D<T> value)
+		where T : unmanaged, INumberBase<T>
+		=> Unsafe.ReadUnaligned<Vector256<double>>(ref Unsafe.As<Vector4D<T>, byte>(ref value));
+	[MethodImpl(AggressiveInlining)]
+	private static Vector4D<T> Store<T>(Vector128<float> value)
+		where T : unmanaged, INumberBase<T>
+		=> Unsafe.As<Vector128<float>, Vector4D<T>>(ref value);
+	[MethodImpl(AggressiveInlining)]
+	private static Vector4D<T> Store<T>(Vector256<double> value)
+		where T : unmanaged, INumberBase<T>
+		=> Unsafe.As<Vector256<double>, Vector4D<T>>(ref value);
+}
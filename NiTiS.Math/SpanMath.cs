@@ -0,0 +1,199 @@
+﻿using System;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Element-wise kernels over flat component spans, vectorized with <see cref="Vector{T}" /> for primitive component types.
+/// </summary>
+internal static class SpanMath
+{
+	[MethodImpl(MethodImplOptions.AggressiveInlining)]
+	public static bool IsVectorizable<T>()
+		=> Vector.IsHardwareAccelerated && (
+			typeof(T) == typeof(float) || typeof(T) == typeof(double)
+			|| typeof(T) == typeof(sbyte) || typeof(T) == typeof(byte)
+			|| typeof(T) == typeof(short) || typeof(T) == typeof(ushort)
+			|| typeof(T) == typeof(int) || typeof(T) == typeof(uint)
+			|| typeof(T) == typeof(long) || typeof(T) == typeof(ulong)
+			|| typeof(T) == typeof(nint) || typeof(T) == typeof(nuint)
+			);
+
+	public static void Add<T>(ReadOnlySpan<T> left, ReadOnlySpan<T> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ValidateBinary(left.Length, right.Length, destination.Length);
+
+		int i = 0;
+		if (IsVectorizable<T>())
+		{
+			for (int last = left.Length - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+				(new Vector<T>(left[i..]) + new Vector<T>(right[i..])).CopyTo(destination[i..]);
+		}
+
+		for (; i < left.Length; i++)
+			destination[i] = left[i] + right[i];
+	}
+	public static void Add<T>(ReadOnlySpan<T> left, T right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ValidateUnary(left.Length, destination.Length);
+
+		int i = 0;
+		if (IsVectorizable<T>())
+		{
+			Vector<T> value = new(right);
+			for (int last = left.Length - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+				(new Vector<T>(left[i..]) + value).CopyTo(destination[i..]);
+		}
+
+		for (; i < left.Length; i++)
+			destination[i] = left[i] + right;
+	}
+
+	public static void Divide<T>(ReadOnlySpan<T> left, ReadOnlySpan<T> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ValidateBinary(left.Length, right.Length, destination.Length);
+
+		int i = 0;
+		if (IsVectorizable<T>())
+		{
+			for (int last = left.Length - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+				(new Vector<T>(left[i..]) / new Vector<T>(right[i..])).CopyTo(destination[i..]);
+		}
+
+		for (; i < left.Length; i++)
+			destination[i] = left[i] / right[i];
+	}
+
+	/// <summary>Componentwise minimum and maximum over consecutive groups of <paramref name="min" />.Length values, <paramref name="values" /> must not be empty.</summary>
+	/// <remarks>Matches <see cref="INumber{TSelf}.Min" /> and <see cref="INumber{TSelf}.Max" />, NaN propagates and negative zero is less than positive zero.</remarks>
+	public static void MinMax<T>(ReadOnlySpan<T> values, Span<T> min, Span<T> max)
+		where T : unmanaged, INumber<T>
+	{
+		int components = min.Length;
+		values[..components].CopyTo(min);
+		values[..components].CopyTo(max);
+
+		// Three vectors span whole groups for up to three components, so each lane always sees the same component
+		int chunk = 3 * Vector<T>.Count;
+
+		int i = 0;
+		if (IsVectorizable<T>() && chunk % components == 0 && values.Length >= chunk)
+		{
+			Vector<T> min0 = new(values), min1 = new(values[Vector<T>.Count..]), min2 = new(values[(2 * Vector<T>.Count)..]);
+			Vector<T> max0 = min0, max1 = min1, max2 = min2;
+
+			for (i = chunk; i <= values.Length - chunk; i += chunk)
+			{
+				Vector<T> v0 = new(values[i..]), v1 = new(values[(i + Vector<T>.Count)..]), v2 = new(values[(i + 2 * Vector<T>.Count)..]);
+
+				min0 = Min(min0, v0);
+				min1 = Min(min1, v1);
+				min2 = Min(min2, v2);
+				max0 = Max(max0, v0);
+				max1 = Max(max1, v1);
+				max2 = Max(max2, v2);
+			}
+
+			Span<T> lows = stackalloc T[chunk];
+			Span<T> highs = stackalloc T[chunk];
+			min0.CopyTo(lows);
+			min1.CopyTo(lows[Vector<T>.Count..]);
+			min2.CopyTo(lows[(2 * Vector<T>.Count)..]);
+			max0.CopyTo(highs);
+			max1.CopyTo(highs[Vector<T>.Count..]);
+			max2.CopyTo(highs[(2 * Vector<T>.Count)..]);
+
+			for (int j = 0; j < chunk; j++)
+			{
+				min[j % components] = T.Min(min[j % components], lows[j]);
+				max[j % components] = T.Max(max[j % components], highs[j]);
+			}
+		}
+
+		for (; i < values.Length; i++)
+		{
+			min[i % components] = T.Min(min[i % components], values[i]);
+			max[i % components] = T.Max(max[i % components], values[i]);
+		}
+	}
+
+	public static void Multiply<T>(ReadOnlySpan<T> left, ReadOnlySpan<T> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ValidateBinary(left.Length, right.Length, destination.Length);
+
+		int i = 0;
+		if (IsVectorizable<T>())
+		{
+			for (int last = left.Length - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+				(new Vector<T>(left[i..]) * new Vector<T>(right[i..])).CopyTo(destination[i..]);
+		}
+
+		for (; i < left.Length; i++)
+			destination[i] = left[i] * right[i];
+	}
+	public static void Multiply<T>(ReadOnlySpan<T> left, T right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ValidateUnary(left.Length, destination.Length);
+
+		int i = 0;
+		if (IsVectorizable<T>())
+		{
+			for (int last = left.Length - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+				(new Vector<T>(left[i..]) * right).CopyTo(destination[i..]);
+		}
+
+		for (; i < left.Length; i++)
+			destination[i] = left[i] * right;
+	}
+
+	public static void Subtract<T>(ReadOnlySpan<T> left, ReadOnlySpan<T> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ValidateBinary(left.Length, right.Length, destination.Length);
+
+		int i = 0;
+		if (IsVectorizable<T>())
+		{
+			for (int last = left.Length - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+				(new Vector<T>(left[i..]) - new Vector<T>(right[i..])).CopyTo(destination[i..]);
+		}
+
+		for (; i < left.Length; i++)
+			destination[i] = left[i] - right[i];
+	}
+
+	public static void ValidateBinary(int left, int right, int destination)
+	{
+		if (left != right)
+			throw new ArgumentException("Span lengths must be equal", nameof(right));
+		ValidateUnary(left, destination);
+	}
+	public static void ValidateUnary(int source, int destination)
+	{
+		if (destination < source)
+			throw new ArgumentException("Destination is too short", nameof(destination));
+	}
+
+	[MethodImpl(MethodImplOptions.AggressiveInlining)]
+	private static Vector<T> Max<T>(Vector<T> left, Vector<T> right)
+		where T : unmanaged
+	{
+		// Vector.Max returns the second operand for NaN and for equal zeros, unlike T.Max
+		Vector<T> max = Vector.ConditionalSelect(Vector.Equals(left, right), left & right, Vector.Max(left, right));
+		return Vector.ConditionalSelect(Vector.Equals(left, left) & Vector.Equals(right, right), max, left + right);
+	}
+	[MethodImpl(MethodImplOptions.AggressiveInlining)]
+	private static Vector<T> Min<T>(Vector<T> left, Vector<T> right)
+		where T : unmanaged
+	{
+		// Vector.Min returns the second operand for NaN and for equal zeros, unlike T.Min
+		Vector<T> min = Vector.ConditionalSelect(Vector.Equals(left, right), left | right, Vector.Min(left, right));
+		return Vector.ConditionalSelect(Vector.Equals(left, left) & Vector.Equals(right, right), min, left + right);
+	}
+}
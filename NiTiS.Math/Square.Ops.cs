@@ -1,16 +1,39 @@
-﻿using System.Numerics;
+﻿using System;
+using System.Numerics;
 using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
 
 public static class Square
 {
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool ApproximatelyEquals<T>(Square<T> left, Square<T> right, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Vector2D.ApproximatelyEquals(left.Min, right.Min, tolerance)
+		&& Vector2D.ApproximatelyEquals(left.Max, right.Max, tolerance);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Square<T> CreateInflated<T>(Square<T> square, Vector2D<T> point)
 		where T : unmanaged, INumber<T>
 		=> new(Vector2D.Min(square.Min, point), Vector2D.Max(square.Max, point));
 
+	/// <summary>Returns the tightest axis-aligned square that contains every point.</summary>
+	public static Square<T> FromPoints<T>(ReadOnlySpan<Vector2D<T>> points)
+		where T : unmanaged, INumber<T>
+	{
+		if (points.IsEmpty)
+			throw new ArgumentException("Point span must not be empty", nameof(points));
+
+		Span<T> min = stackalloc T[2];
+		Span<T> max = stackalloc T[2];
+
+		SpanMath.MinMax(MemoryMarshal.Cast<Vector2D<T>, T>(points), min, max);
+
+		return new(min[0], min[1], max[0], max[1]);
+	}
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T GetDistanceToNearestEdge<T>(Square<T> box, Vector2D<T> point)
 		where T : unmanaged, INumber<T>, IRootFunctions<T>
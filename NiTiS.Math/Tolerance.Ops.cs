@@ -0,0 +1,28 @@
+﻿using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+public static class Tolerance
+{
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Tolerance<T> Absolute<T>(T epsilon)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Tolerance<T>.Absolute(epsilon);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Tolerance<T> Combined<T>(T absolute, T relative)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Tolerance<T>.Combined(absolute, relative);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Tolerance<T> FromUlps<T>(int ulps)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Tolerance<T>.FromUlps(ulps);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Tolerance<T> Relative<T>(T epsilon)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Tolerance<T>.Relative(epsilon);
+}
@@ -0,0 +1,136 @@
+﻿using System;
+using System.Diagnostics;
+using System.Diagnostics.CodeAnalysis;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Describes how far apart two floating-point values may be to still count as equal.
+/// </summary>
+[DebuggerDisplay($@"{{{nameof(ToString)}(),nq}}")]
+public readonly struct Tolerance<T> :
+	IEquatable<Tolerance<T>>
+	where T : unmanaged, IFloatingPointIeee754<T>
+{
+	public readonly ToleranceMode Mode;
+	/// <summary>Maximal difference for <see cref="ToleranceMode.Absolute" /> and <see cref="ToleranceMode.Relative" />, the absolute floor for <see cref="ToleranceMode.Combined" />.</summary>
+	public readonly T Epsilon;
+	/// <summary>Maximal difference relative to the larger magnitude for <see cref="ToleranceMode.Combined" />.</summary>
+	public readonly T RelativeEpsilon;
+	/// <summary>Maximal count of representable steps between the values for <see cref="ToleranceMode.Ulps" />.</summary>
+	public readonly int Ulps;
+
+	private Tolerance(ToleranceMode mode, T epsilon, T relativeEpsilon, int ulps)
+		=> (Mode, Epsilon, RelativeEpsilon, Ulps) = (mode, epsilon, relativeEpsilon, ulps);
+
+	public static Tolerance<T> Absolute(T epsilon)
+	{
+		if (!(epsilon >= T.Zero))
+			throw new ArgumentOutOfRangeException(nameof(epsilon));
+
+		return new(ToleranceMode.Absolute, epsilon, T.Zero, 0);
+	}
+	/// <remarks>Only zero is relatively close to zero, use <see cref="Combined" /> when values may be near zero.</remarks>
+	public static Tolerance<T> Relative(T epsilon)
+	{
+		if (!(epsilon >= T.Zero))
+			throw new ArgumentOutOfRangeException(nameof(epsilon));
+
+		return new(ToleranceMode.Relative, epsilon, T.Zero, 0);
+	}
+	/// <summary>Accepts differences within <paramref name="absolute" /> or within <paramref name="relative" /> of the larger magnitude, whichever is wider.</summary>
+	public static Tolerance<T> Combined(T absolute, T relative)
+	{
+		if (!(absolute >= T.Zero))
+			throw new ArgumentOutOfRangeException(nameof(absolute));
+		if (!(relative >= T.Zero))
+			throw new ArgumentOutOfRangeException(nameof(relative));
+
+		return new(ToleranceMode.Combined, absolute, relative, 0);
+	}
+	public static Tolerance<T> FromUlps(int ulps)
+	{
+		if (ulps < 0)
+			throw new ArgumentOutOfRangeException(nameof(ulps));
+
+		return new(ToleranceMode.Ulps, T.Zero, T.Zero, ulps);
+	}
+
+	/// <summary>Returns whether both values are within this tolerance, <c>NaN</c> never equals anything.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public readonly bool AreEqual(T left, T right)
+	{
+		// Also covers equal infinities, which produce NaN when subtracted
+		if (left == right)
+			return true;
+
+		// An infinity is never close to a finite value, and NaN is never close to anything
+		if (!T.IsFinite(left) || !T.IsFinite(right))
+			return false;
+
+		T difference = T.Abs(left - right);
+
+		return Mode switch
+		{
+			ToleranceMode.Absolute => difference <= Epsilon,
+			ToleranceMode.Relative => difference <= Epsilon * T.Max(T.Abs(left), T.Abs(right)),
+			ToleranceMode.Combined => difference <= T.Max(Epsilon, RelativeEpsilon * T.Max(T.Abs(left), T.Abs(right))),
+			_ => AreEqualUlps(left, right),
+		};
+	}
+
+	private readonly bool AreEqualUlps(T left, T right)
+	{
+		long a = ToOrdered(left);
+		long b = ToOrdered(right);
+
+		// Same sign, the difference cannot overflow
+		if ((a < 0) == (b < 0))
+			return (ulong)SMath.Abs(a - b) <= (ulong)Ulps;
+
+		// Differing signs, the distance is the sum of both distances to zero
+		ulong negative = (ulong)-SMath.Min(a, b);
+		ulong positive = (ulong)SMath.Max(a, b);
+
+		return negative <= (ulong)Ulps && positive <= (ulong)Ulps - negative;
+	}
+
+	/// <summary>Maps the bit pattern onto an integer line where neighbouring values differ by one and both zeros are <c>0</c>.</summary>
+	private static long ToOrdered(T value)
+	{
+		if (Unsafe.SizeOf<T>() == sizeof(long))
+		{
+			long bits = Unsafe.As<T, long>(ref value);
+			return bits < 0 ? long.MinValue - bits : bits;
+		}
+		else if (Unsafe.SizeOf<T>() == sizeof(int))
+		{
+			int bits = Unsafe.As<T, int>(ref value);
+			return bits < 0 ? int.MinValue - (long)bits : bits;
+		}
+		else if (Unsafe.SizeOf<T>() == sizeof(short))
+		{
+			short bits = Unsafe.As<T, short>(ref value);
+			return bits < 0 ? short.MinValue - (long)bits : bits;
+		}
+
+		throw new NotSupportedException($"{typeof(T)} is not a binary interchange format");
+	}
+
+	public override readonly int GetHashCode()
+		=> HashCode.Combine(Mode, Epsilon, RelativeEpsilon, Ulps);
+	public override readonly bool Equals([NotNullWhen(true)] object? obj)
+		=> obj is Tolerance<T> tolerance && Equals(tolerance);
+	public readonly bool Equals(Tolerance<T> other)
+		=> Mode == other.Mode && Epsilon == other.Epsilon && RelativeEpsilon == other.RelativeEpsilon && Ulps == other.Ulps;
+	public override readonly string ToString()
+		=> Mode switch
+		{
+			ToleranceMode.Ulps => $"{Ulps} ulps",
+			ToleranceMode.Combined => $"{Mode} {Epsilon} {RelativeEpsilon}",
+			_ => $"{Mode} {Epsilon}",
+		};
+}
@@ -0,0 +1,16 @@
+﻿namespace NiTiS.Math;
+
+/// <summary>
+/// Describes how <see cref="Tolerance{T}" /> measures the distance between two values.
+/// </summary>
+public enum ToleranceMode
+{
+	/// <summary>The difference must not exceed a fixed epsilon.</summary>
+	Absolute,
+	/// <summary>The difference must not exceed an epsilon scaled by the larger magnitude.</summary>
+	Relative,
+	/// <summary>The number of representable values between both values must not exceed a count.</summary>
+	Ulps,
+	/// <summary>The difference must not exceed either a fixed epsilon or an epsilon scaled by the larger magnitude.</summary>
+	Combined,
+}
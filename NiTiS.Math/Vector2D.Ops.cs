@@ -1,5 +1,7 @@
-﻿using System.Numerics;
+﻿using System;
+using System.Numerics;
 using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
@@ -22,6 +24,33 @@ public static class Vector2D
 	public static Vector2D<T> Add<T>(Vector2D<T> left, T right)
 		where T : unmanaged, INumberBase<T>
 		=> left + right;
+	/// <summary>Adds every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Add<T>(ReadOnlySpan<Vector2D<T>> left, ReadOnlySpan<Vector2D<T>> right, Span<Vector2D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Add(MemoryMarshal.Cast<Vector2D<T>, T>(left), MemoryMarshal.Cast<Vector2D<T>, T>(right), MemoryMarshal.Cast<Vector2D<T>, T>(destination));
+
+	/// <summary>Returns the angle from the positive X axis to the vector, in <c>(-π, π]</c>.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Math.Angle<T> Angle<T>(Vector2D<T> vector)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Math.Angle<T>.FromRadians(T.Atan2(vector.Y, vector.X));
+
+	/// <summary>Returns the unsigned angle between the vectors, in <c>[0, π]</c>.</summary>
+	public static Math.Angle<T> AngleBetween<T>(Vector2D<T> from, Vector2D<T> to)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		// Kahan's formula stays accurate for nearly parallel and nearly opposite vectors, unlike acos of the dot product
+		Vector2D<T> a = from * Length(to);
+		Vector2D<T> b = to * Length(from);
+
+		return Math.Angle<T>.FromRadians(Scalar<T>.Two * T.Atan2(Length(a - b), Length(a + b)));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool ApproximatelyEquals<T>(Vector2D<T> left, Vector2D<T> right, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> tolerance.AreEqual(left.X, right.X)
+		&& tolerance.AreEqual(left.Y, right.Y);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> Bitwise<T>(Vector2D<T> operand)
@@ -31,6 +60,14 @@ public static class Vector2D
 			~operand.Y
 			);
 
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Ceiling<T>(Vector2D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Ceiling(operand.X),
+			T.Ceiling(operand.Y)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> Clamp<T>(Vector2D<T> value, Vector2D<T> min, Vector2D<T> max)
 		where T : unmanaged, INumberBase<T>, INumber<T>
@@ -39,6 +76,41 @@ public static class Vector2D
 			T.Clamp(value.Y, min.Y, max.Y)
 			);
 
+	/// <summary>Scales the vector down so its length does not exceed <paramref name="maxLength" />.</summary>
+	public static Vector2D<T> ClampLength<T>(Vector2D<T> vector, T maxLength)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T lengthSquared = vector.LengthSquared;
+
+		if (lengthSquared <= maxLength * maxLength)
+			return vector;
+
+		return vector * (maxLength / T.Sqrt(lengthSquared));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CopySign<T>(Vector2D<T> value, Vector2D<T> sign)
+		where T : unmanaged, INumber<T>
+		=> new(
+			T.CopySign(value.X, sign.X),
+			T.CopySign(value.Y, sign.Y)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CopySign<T>(Vector2D<T> value, T sign)
+		where T : unmanaged, INumber<T>
+		=> new(
+			T.CopySign(value.X, sign),
+			T.CopySign(value.Y, sign)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Cos<T>(Vector2D<T> operand)
+		where T : unmanaged, ITrigonometricFunctions<T>
+		=> new(
+			T.Cos(operand.X),
+			T.Cos(operand.Y)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T Distance<T>(Vector2D<T> from, Vector2D<T> to)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -72,6 +144,58 @@ public static class Vector2D
 	public static T Dot<T>(Vector2D<T> left, Vector2D<T> right)
 		where T : unmanaged, INumberBase<T>
 		=> left.X * right.X + left.Y * right.Y;
+	/// <summary>Writes the dot product of every pair of vectors into <paramref name="destination" />.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector2DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void Dot<T>(ReadOnlySpan<Vector2D<T>> left, ReadOnlySpan<Vector2D<T>> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		SpanMath.ValidateBinary(left.Length, right.Length, destination.Length);
+
+		for (int i = 0; i < left.Length; i++)
+			destination[i] = Dot(left[i], right[i]);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Exp<T>(Vector2D<T> operand)
+		where T : unmanaged, IExponentialFunctions<T>
+		=> new(
+			T.Exp(operand.X),
+			T.Exp(operand.Y)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Floor<T>(Vector2D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Floor(operand.X),
+			T.Floor(operand.Y)
+			);
+
+	/// <summary>Returns <c>operand - Floor(operand)</c> for every component, the result is in <c>[0, 1]</c> as tiny negative components round up to <c>1</c>.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Fract<T>(Vector2D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Fract(operand.X),
+			Scalar.Fract(operand.Y)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> FromPolar<T>(T radius, Math.Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		(T sin, T cos) = T.SinCos(angle.Radians);
+		return new(radius * cos, radius * sin);
+	}
+
+	/// <summary>Computes <c>(left * right) + addend</c> for every component, rounded as one ternary operation.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> FusedMultiplyAdd<T>(Vector2D<T> left, Vector2D<T> right, Vector2D<T> addend)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(
+			T.FusedMultiplyAdd(left.X, right.X, addend.X),
+			T.FusedMultiplyAdd(left.Y, right.Y, addend.Y)
+			);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T Length<T>(Vector2D<T> operand)
@@ -83,6 +207,14 @@ public static class Vector2D
 		where T : unmanaged, INumberBase<T>
 		=> (left * (T.One - amount)) + (right * amount);
 
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Log<T>(Vector2D<T> operand)
+		where T : unmanaged, ILogarithmicFunctions<T>
+		=> new(
+			T.Log(operand.X),
+			T.Log(operand.Y)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> Max<T>(Vector2D<T> left, Vector2D<T> right)
 		where T : unmanaged, INumber<T>
@@ -99,6 +231,35 @@ public static class Vector2D
 			T.Min(left.Y, right.Y)
 			);
 
+	/// <summary>Floored modulo, unlike <see cref="Rem{T}(Vector2D{T}, Vector2D{T})" /> the result has the sign of <paramref name="right" />, its magnitude may reach <paramref name="right" /> through rounding.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Mod<T>(Vector2D<T> left, Vector2D<T> right)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Mod(left.X, right.X),
+			Scalar.Mod(left.Y, right.Y)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Mod<T>(Vector2D<T> left, T right)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Mod(left.X, right),
+			Scalar.Mod(left.Y, right)
+			);
+
+	/// <summary>Moves <paramref name="current" /> towards <paramref name="target" /> by at most <paramref name="maxDistance" />, without overshooting.</summary>
+	public static Vector2D<T> MoveTowards<T>(Vector2D<T> current, Vector2D<T> target, T maxDistance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Vector2D<T> difference = target - current;
+		T distance = Length(difference);
+
+		if (distance <= maxDistance || distance == T.Zero)
+			return target;
+
+		return current + difference * (maxDistance / distance);
+	}
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> Multiply<T>(Vector2D<T> left, Vector2D<T> right)
 		where T : unmanaged, INumberBase<T>
@@ -114,6 +275,14 @@ public static class Vector2D
 			left * right.X,
 			left * right.Y
 			);
+	/// <summary>Multiplies every pair of vectors component-wise into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(ReadOnlySpan<Vector2D<T>> left, ReadOnlySpan<Vector2D<T>> right, Span<Vector2D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Multiply(MemoryMarshal.Cast<Vector2D<T>, T>(left), MemoryMarshal.Cast<Vector2D<T>, T>(right), MemoryMarshal.Cast<Vector2D<T>, T>(destination));
+	/// <summary>Scales every vector into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(ReadOnlySpan<Vector2D<T>> left, T right, Span<Vector2D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Multiply(MemoryMarshal.Cast<Vector2D<T>, T>(left), right, MemoryMarshal.Cast<Vector2D<T>, T>(destination));
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> Negate<T>(Vector2D<T> operand)
@@ -124,12 +293,304 @@ public static class Vector2D
 	public static Vector2D<T> Normalize<T>(Vector2D<T> operand)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
 		=> operand / Length(operand);
+	/// <summary>Normalizes every vector into <paramref name="destination" />, which may be the source span itself.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector2DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void Normalize<T>(ReadOnlySpan<Vector2D<T>> source, Span<Vector2D<T>> destination)
+		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
+	{
+		SpanMath.ValidateUnary(source.Length, destination.Length);
+
+		for (int i = 0; i < source.Length; i++)
+			destination[i] = Normalize(source[i]);
+	}
+
+	/// <summary>Returns whether <paramref name="a" />, <paramref name="b" />, <paramref name="c" /> turn clockwise, counterclockwise or lie on one line.</summary>
+	/// <remarks>
+	/// Nearly collinear points are not misclassified by rounding or wrapping:
+	/// <see langword="float" /> and <see cref="Half" /> components are always exact, integer components are widened to <see cref="Int128" /> and exact while coordinate differences fit in 63 bits,
+	/// <see langword="double" /> components are exact while the products of coordinate differences neither overflow nor underflow, an overflowing product gives <see cref="Orientation.Collinear" />.
+	/// Other component types are evaluated in <typeparamref name="T" /> as is. Any NaN component gives <see cref="Orientation.Collinear" />.
+	/// </remarks>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Orientation Orient2D<T>(Vector2D<T> a, Vector2D<T> b, Vector2D<T> c)
+		where T : unmanaged, INumber<T>
+	{
+		// These convert to double without rounding
+		if (typeof(T) == typeof(double) || typeof(T) == typeof(float) || typeof(T) == typeof(Half) || typeof(T) == typeof(NFloat))
+		{
+			return Orient2D(
+				double.CreateTruncating(a.X), double.CreateTruncating(a.Y),
+				double.CreateTruncating(b.X), double.CreateTruncating(b.Y),
+				double.CreateTruncating(c.X), double.CreateTruncating(c.Y)
+				);
+		}
+
+		if (typeof(T) == typeof(sbyte) || typeof(T) == typeof(byte)
+			|| typeof(T) == typeof(short) || typeof(T) == typeof(ushort) || typeof(T) == typeof(char)
+			|| typeof(T) == typeof(int) || typeof(T) == typeof(uint)
+			|| typeof(T) == typeof(long) || typeof(T) == typeof(ulong)
+			|| typeof(T) == typeof(nint) || typeof(T) == typeof(nuint))
+		{
+			// Differences and products of narrower integers wrap in T
+			Int128 abX = Int128.CreateTruncating(b.X) - Int128.CreateTruncating(a.X), abY = Int128.CreateTruncating(b.Y) - Int128.CreateTruncating(a.Y);
+			Int128 acX = Int128.CreateTruncating(c.X) - Int128.CreateTruncating(a.X), acY = Int128.CreateTruncating(c.Y) - Int128.CreateTruncating(a.Y);
+
+			return (Orientation)Int128.Sign(abX * acY - abY * acX);
+		}
+
+		T det = PerpDot(b - a, c - a);
+		return T.IsNaN(det)
+			? Orientation.Collinear
+			: (Orientation)T.Sign(det);
+	}
+	private static Orientation Orient2D(double ax, double ay, double bx, double by, double cx, double cy)
+	{
+		double detLeft = (ax - cx) * (by - cy);
+		double detRight = (ay - cy) * (bx - cx);
+		double det = detLeft - detRight;
+
+		if (double.IsNaN(det))
+			return Orientation.Collinear;
+
+		// Shewchuk's error bound of the rounded determinant, (3 + 16e) * e with e = 2^-53
+		double bound = 3.3306690738754716e-16 * (double.Abs(detLeft) + double.Abs(detRight));
+		if (det > bound)
+			return Orientation.CounterClockwise;
+		if (-det > bound)
+			return Orientation.Clockwise;
+
+		// Too close to call, sum a x b + b x c + c x a exactly as an expansion of the rounded products and their errors
+		Span<double> expansion = stackalloc double[12];
+		int length = 0;
+
+		Grow(expansion, ref length, ax * by, double.FusedMultiplyAdd(ax, by, -(ax * by)));
+		Grow(expansion, ref length, -(ay * bx), -double.FusedMultiplyAdd(ay, bx, -(ay * bx)));
+		Grow(expansion, ref length, bx * cy, double.FusedMultiplyAdd(bx, cy, -(bx * cy)));
+		Grow(expansion, ref length, -(by * cx), -double.FusedMultiplyAdd(by, cx, -(by * cx)));
+		Grow(expansion, ref length, cx * ay, double.FusedMultiplyAdd(cx, ay, -(cx * ay)));
+		Grow(expansion, ref length, -(cy * ax), -double.FusedMultiplyAdd(cy, ax, -(cy * ax)));
+
+		// Components are nonoverlapping in increasing magnitude, the largest nonzero one decides the sign
+		for (int i = length - 1; i >= 0; i--)
+		{
+			if (expansion[i] > 0)
+				return Orientation.CounterClockwise;
+			if (expansion[i] < 0)
+				return Orientation.Clockwise;
+		}
+
+		return Orientation.Collinear;
+
+		static void Grow(Span<double> expansion, ref int length, double product, double error)
+		{
+			Add(expansion, ref length, error);
+			Add(expansion, ref length, product);
+		}
+		static void Add(Span<double> expansion, ref int length, double value)
+		{
+			for (int i = 0; i < length; i++)
+			{
+				// Knuth's two-sum keeps the rounding error of every partial sum
+				double sum = value + expansion[i];
+				double virtualValue = sum - expansion[i];
+				double virtualComponent = sum - virtualValue;
+				expansion[i] = (value - virtualValue) + (expansion[i] - virtualComponent);
+				value = sum;
+			}
+
+			expansion[length++] = value;
+		}
+	}
+
+	/// <summary>Normalizes <paramref name="normal" /> and makes <paramref name="tangent" /> a unit vector perpendicular to it (Gram-Schmidt).</summary>
+	public static void OrthoNormalize<T>(ref Vector2D<T> normal, ref Vector2D<T> tangent)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		normal = Normalize(normal);
+		tangent = Normalize(Reject(tangent, normal));
+	}
+
+	/// <summary>Returns the Z component of the cross product of the vectors extended with zero Z, positive when <paramref name="right" /> is counterclockwise from <paramref name="left" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static T PerpDot<T>(Vector2D<T> left, Vector2D<T> right)
+		where T : unmanaged, INumberBase<T>
+		=> left.X * right.Y - left.Y * right.X;
+
+	/// <summary>Returns a vector perpendicular to the operand, the same as <see cref="PerpendicularLeft{T}(Vector2D{T})" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Perpendicular<T>(Vector2D<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> PerpendicularLeft(operand);
+	/// <summary>Returns the vector rotated by 90° counterclockwise.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> PerpendicularLeft<T>(Vector2D<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> new(-operand.Y, operand.X);
+	/// <summary>Returns the vector rotated by 90° clockwise.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> PerpendicularRight<T>(Vector2D<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> new(operand.Y, -operand.X);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Pow<T>(Vector2D<T> value, Vector2D<T> exponent)
+		where T : unmanaged, IPowerFunctions<T>
+		=> new(
+			T.Pow(value.X, exponent.X),
+			T.Pow(value.Y, exponent.Y)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Pow<T>(Vector2D<T> value, T exponent)
+		where T : unmanaged, IPowerFunctions<T>
+		=> new(
+			T.Pow(value.X, exponent),
+			T.Pow(value.Y, exponent)
+			);
+
+	/// <summary>Returns the component of <paramref name="vector" /> parallel to <paramref name="onto" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Project<T>(Vector2D<T> vector, Vector2D<T> onto)
+		where T : unmanaged, INumberBase<T>
+		=> onto * (Dot(vector, onto) / Dot(onto, onto));
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> Reflect<T>(Vector2D<T> vector, Vector2D<T> normal)
 		where T : unmanaged, INumberBase<T>
 		=> vector - (Scalar<T>.Two * Dot(vector, normal)) * normal;
 
+	/// <summary>Refracts the normalized <paramref name="incident" /> direction through a surface with the given normal.</summary>
+	/// <param name="eta">Ratio of the refractive indices, the one being left over the one being entered.</param>
+	/// <returns>The refracted direction, or zero on total internal reflection.</returns>
+	public static Vector2D<T> Refract<T>(Vector2D<T> incident, Vector2D<T> normal, T eta)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T cos = Dot(normal, incident);
+		T k = T.One - eta * eta * (T.One - cos * cos);
+
+		if (k < T.Zero)
+			return Vector2D<T>.Zero;
+
+		return incident * eta - normal * (eta * cos + T.Sqrt(k));
+	}
+
+	/// <summary>Returns the component of <paramref name="vector" /> perpendicular to <paramref name="onto" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Reject<T>(Vector2D<T> vector, Vector2D<T> onto)
+		where T : unmanaged, INumberBase<T>
+		=> vector - Project(vector, onto);
+
+	/// <summary>Truncated remainder, the result has the sign of <paramref name="left" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Rem<T>(Vector2D<T> left, Vector2D<T> right)
+		where T : unmanaged, INumber<T>
+		=> new(
+			left.X % right.X,
+			left.Y % right.Y
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Rem<T>(Vector2D<T> left, T right)
+		where T : unmanaged, INumber<T>
+		=> new(
+			left.X % right,
+			left.Y % right
+			);
+
+	/// <summary>Rotates the vector counterclockwise by the given angle.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Rotate<T>(Vector2D<T> vector, Math.Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		(T sin, T cos) = T.SinCos(angle.Radians);
+		return new(
+			vector.X * cos - vector.Y * sin,
+			vector.X * sin + vector.Y * cos
+			);
+	}
+
+	/// <summary>Rotates the <paramref name="from" /> direction towards <paramref name="to" /> by at most <paramref name="maxAngle" />, without overshooting.</summary>
+	public static Vector2D<T> RotateTowards<T>(Vector2D<T> from, Vector2D<T> to, Math.Angle<T> maxAngle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Math.Angle<T> angle = AngleBetween(from, to);
+
+		if (angle <= maxAngle)
+			return to;
+
+		return Slerp(from, to, maxAngle / angle);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Round<T>(Vector2D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Round(operand.X),
+			T.Round(operand.Y)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Round<T>(Vector2D<T> operand, MidpointRounding mode)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Round(operand.X, mode),
+			T.Round(operand.Y, mode)
+			);
+
+	/// <summary>Returns <c>-1</c>, <c>0</c> or <c>1</c> for every component, NaN components stay NaN.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Sign<T>(Vector2D<T> operand)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Sign(operand.X),
+			Scalar.Sign(operand.Y)
+			);
+
+	/// <summary>Returns the angle from <paramref name="from" /> to <paramref name="to" />, in <c>(-π, π]</c>, positive counterclockwise.</summary>
+	public static Math.Angle<T> SignedAngle<T>(Vector2D<T> from, Vector2D<T> to)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Math.Angle<T>.FromRadians(T.Atan2(PerpDot(from, to), Dot(from, to)));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Sin<T>(Vector2D<T> operand)
+		where T : unmanaged, ITrigonometricFunctions<T>
+		=> new(
+			T.Sin(operand.X),
+			T.Sin(operand.Y)
+			);
+
+	/// <summary>Spherically interpolates between two normalized directions at constant angular speed.</summary>
+	public static Vector2D<T> Slerp<T>(Vector2D<T> from, Vector2D<T> to, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T epsilon = T.CreateTruncating(1e-6);
+		T cos = T.Clamp(Dot(from, to), T.NegativeOne, T.One);
+
+		if (cos > T.One - epsilon)
+			return Normalize(Lerp(from, to, amount));
+
+		// Opposite directions have no unique arc, so any perpendicular one is taken
+		Vector2D<T> ortho = cos < epsilon - T.One
+			? Normalize(Perpendicular(from))
+			: Normalize(to - from * cos);
+
+		T angle = T.Acos(cos) * amount;
+		return from * T.Cos(angle) + ortho * T.Sin(angle);
+	}
+
+	/// <summary>Hermite interpolation between <c>0</c> and <c>1</c> for every component of <paramref name="value" /> between the edges.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> SmoothStep<T>(Vector2D<T> edge0, Vector2D<T> edge1, Vector2D<T> value)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.SmoothStep(edge0.X, edge1.X, value.X),
+			Scalar.SmoothStep(edge0.Y, edge1.Y, value.Y)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> SmoothStep<T>(T edge0, T edge1, Vector2D<T> value)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.SmoothStep(edge0, edge1, value.X),
+			Scalar.SmoothStep(edge0, edge1, value.Y)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> SquareRoot<T>(Vector2D<T> operand)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -138,6 +599,22 @@ public static class Vector2D
 			T.Sqrt(operand.Y)
 			);
 
+	/// <summary>Returns <c>0</c> for every component of <paramref name="value" /> below <paramref name="edge" />, otherwise <c>1</c>.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Step<T>(Vector2D<T> edge, Vector2D<T> value)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Step(edge.X, value.X),
+			Scalar.Step(edge.Y, value.Y)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Step<T>(T edge, Vector2D<T> value)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Step(edge, value.X),
+			Scalar.Step(edge, value.Y)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> Substract<T>(Vector2D<T> left, Vector2D<T> right)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -145,4 +622,60 @@ public static class Vector2D
 			left.X - right.X,
 			left.Y - right.Y
 			);
+	/// <summary>Subtracts every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Substract<T>(ReadOnlySpan<Vector2D<T>> left, ReadOnlySpan<Vector2D<T>> right, Span<Vector2D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Subtract(MemoryMarshal.Cast<Vector2D<T>, T>(left), MemoryMarshal.Cast<Vector2D<T>, T>(right), MemoryMarshal.Cast<Vector2D<T>, T>(destination));
+
+	public static void ToPolar<T>(Vector2D<T> vector, out T radius, out Math.Angle<T> angle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		radius = Length(vector);
+		angle = Angle(vector);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Transform<T>(Vector2D<T> position, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			position.X * matrix.M11 + position.Y * matrix.M21 + matrix.M41,
+			position.X * matrix.M12 + position.Y * matrix.M22 + matrix.M42
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Transform<T>(Vector2D<T> position, Mat2<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			position.X * matrix.M11 + position.Y * matrix.M21,
+			position.X * matrix.M12 + position.Y * matrix.M22
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Transform<T>(Vector2D<T> position, Mat3x2<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			position.X * matrix.M11 + position.Y * matrix.M21 + matrix.M31,
+			position.X * matrix.M12 + position.Y * matrix.M22 + matrix.M32
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> TransformNormal<T>(Vector2D<T> normal, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			normal.X * matrix.M11 + normal.Y * matrix.M21,
+			normal.X * matrix.M12 + normal.Y * matrix.M22
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> TransformNormal<T>(Vector2D<T> normal, Mat3x2<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			normal.X * matrix.M11 + normal.Y * matrix.M21,
+			normal.X * matrix.M12 + normal.Y * matrix.M22
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> Truncate<T>(Vector2D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Truncate(operand.X),
+			T.Truncate(operand.Y)
+			);
 }
\ No newline at end of file
@@ -6,13 +6,12 @@ using System.Globalization;
 using System.Numerics;
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
-using System.Text;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
 
 [DebuggerDisplay($@"{{{nameof(ToString)}(""G""),nq}}")]
-public readonly unsafe struct Vector2D<T> :
+public readonly unsafe partial struct Vector2D<T> :
 	// Vector op Vector
 	IAdditionOperators<Vector2D<T>, Vector2D<T>, Vector2D<T>>,
 	ISubtractionOperators<Vector2D<T>, Vector2D<T>, Vector2D<T>>,
@@ -27,7 +26,11 @@ public readonly unsafe struct Vector2D<T> :
 	// Unary op
 	IUnaryNegationOperators<Vector2D<T>, Vector2D<T>>,
 	IUnaryPlusOperators<Vector2D<T>, Vector2D<T>>,
-	IFormattable,
+	ISpanFormattable,
+#if NET8_0_OR_GREATER
+	IUtf8SpanFormattable,
+#endif
+	ISpanParsable<Vector2D<T>>,
 	IEquatable<Vector2D<T>>,
 	// Cast op
 	IExplicitCastOperators<Vector2D<T>, Vector3D<T>>,
@@ -84,6 +87,51 @@ public readonly unsafe struct Vector2D<T> :
 	public static Vector2D<T> UnitX => new(T.One, T.Zero);
 	public static Vector2D<T> UnitY => new(T.Zero, T.One);
 
+	/// <summary>Converts every component, throwing <see cref="OverflowException" /> when it is not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CreateChecked<TOther>(Vector2D<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(T.CreateChecked(value.X), T.CreateChecked(value.Y));
+	/// <summary>Converts every component, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CreateSaturating<TOther>(Vector2D<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(T.CreateSaturating(value.X), T.CreateSaturating(value.Y));
+	/// <summary>Converts every component, truncating values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CreateTruncating<TOther>(Vector2D<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(T.CreateTruncating(value.X), T.CreateTruncating(value.Y));
+	/// <summary>Rounds every component up and converts it, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CreateCeiling<TOther>(Vector2D<TOther> value)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> new(
+			T.CreateSaturating(TOther.Ceiling(value.X)),
+			T.CreateSaturating(TOther.Ceiling(value.Y))
+			);
+	/// <summary>Rounds every component down and converts it, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CreateFloor<TOther>(Vector2D<TOther> value)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> new(
+			T.CreateSaturating(TOther.Floor(value.X)),
+			T.CreateSaturating(TOther.Floor(value.Y))
+			);
+	/// <summary>Rounds every component to the nearest integer and converts it, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CreateRounded<TOther>(Vector2D<TOther> value)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> CreateRounded(value, MidpointRounding.ToEven);
+	/// <inheritdoc cref="CreateRounded{TOther}(Vector2D{TOther})" />
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector2D<T> CreateRounded<TOther>(Vector2D<TOther> value, MidpointRounding mode)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> new(
+			T.CreateSaturating(TOther.Round(value.X, mode)),
+			T.CreateSaturating(TOther.Round(value.Y, mode))
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector2D<T> operator +(Vector2D<T> left, Vector2D<T> right)
 		=> new(
@@ -183,6 +231,11 @@ public readonly unsafe struct Vector2D<T> :
 			T.Zero
 			);
 
+	/// <summary>Returns a copy of the vector with the specified components replaced.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public readonly Vector2D<T> With(T? x = null, T? y = null)
+		=> new(x ?? X, y ?? Y);
+
 	public readonly void CopyTo(T[] array)
 		=> CopyTo(array, 0);
 	public readonly void CopyTo(T[] array, uint offset)
@@ -212,16 +265,51 @@ public readonly unsafe struct Vector2D<T> :
 	public override readonly string ToString() => ToString("G", CultureInfo.CurrentCulture);
 	public readonly string ToString(string? format) => ToString(format, CultureInfo.CurrentCulture);
 	public readonly string ToString(string? format, IFormatProvider? formatProvider)
+		=> Formatting.ToString(this, format, formatProvider);
+	public readonly bool TryFormat(Span<char> destination, out int charsWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatVector(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in X), ElementCount), destination, out charsWritten, format, provider);
+#if NET8_0_OR_GREATER
+	public readonly bool TryFormat(Span<byte> utf8Destination, out int bytesWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatVector(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in X), ElementCount), utf8Destination, out bytesWritten, format, provider);
+#endif
+
+	public static Vector2D<T> Parse(string s)
+		=> Parse(s, CultureInfo.CurrentCulture);
+	/// <summary>Parses a vector written by <see cref="ToString(string?, IFormatProvider?)" /> with the same provider, <c>(x, y)</c> and whitespace separated forms are accepted too.</summary>
+	/// <exception cref="FormatException"><paramref name="s" /> is not a vector.</exception>
+	public static Vector2D<T> Parse(string s, IFormatProvider? provider)
+	{
+		ArgumentNullException.ThrowIfNull(s);
+		return Parse(s.AsSpan(), provider);
+	}
+	/// <inheritdoc cref="Parse(string, IFormatProvider?)" />
+	public static Vector2D<T> Parse(ReadOnlySpan<char> s, IFormatProvider? provider)
+		=> TryParse(s, provider, out Vector2D<T> result)
+		? result
+		: throw new FormatException("Input string was not in a correct format");
+	public static bool TryParse([NotNullWhen(true)] string? s, out Vector2D<T> result)
+		=> TryParse(s, CultureInfo.CurrentCulture, out result);
+	public static bool TryParse([NotNullWhen(true)] string? s, IFormatProvider? provider, out Vector2D<T> result)
+	{
+		if (s is null)
+		{
+			result = default;
+			return false;
+		}
+
+		return TryParse(s.AsSpan(), provider, out result);
+	}
+	public static bool TryParse(ReadOnlySpan<char> s, IFormatProvider? provider, out Vector2D<T> result)
 	{
-		StringBuilder sb = new();
-		string separator = NumberFormatInfo.GetInstance(formatProvider).NumberGroupSeparator;
-		sb.Append('<');
-		sb.Append(X.ToString(format, formatProvider));
-		sb.Append(separator);
-		if (!string.IsNullOrWhiteSpace(separator))
-			sb.Append(' ');
-		sb.Append(Y.ToString(format, formatProvider));
-		sb.Append('>');
-		return sb.ToString();
+		Span<T> components = stackalloc T[ElementCount];
+
+		if (!VectorParser.TryParse(s, provider, components))
+		{
+			result = default;
+			return false;
+		}
+
+		result = new(components);
+		return true;
 	}
 }
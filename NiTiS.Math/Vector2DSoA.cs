@@ -0,0 +1,88 @@
+﻿using System;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Heap-backed collection of <see cref="Vector2D{T}" /> stored as a structure of arrays, every component is a contiguous span.
+/// </summary>
+public sealed class Vector2DSoA<T>
+	where T : unmanaged, INumberBase<T>
+{
+	private readonly T[] data;
+
+	public int Count { get; }
+	public Span<T> X => data.AsSpan(0, Count);
+	public Span<T> Y => data.AsSpan(Count, Count);
+
+	public Vector2DSoA(int count)
+	{
+		if (count < 0)
+			throw new ArgumentOutOfRangeException(nameof(count));
+
+		Count = count;
+		data = new T[count * 2];
+	}
+	public Vector2DSoA(ReadOnlySpan<Vector2D<T>> vectors)
+		: this(vectors.Length)
+	{
+		Span<T> x = X, y = Y;
+
+		for (int i = 0; i < vectors.Length; i++)
+			(x[i], y[i]) = (vectors[i].X, vectors[i].Y);
+	}
+
+	public Vector2D<T> this[int index]
+	{
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		get
+		{
+			if ((uint)index >= (uint)Count)
+				throw new ArgumentOutOfRangeException(nameof(index));
+
+			return new(data[index], data[Count + index]);
+		}
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		set
+		{
+			if ((uint)index >= (uint)Count)
+				throw new ArgumentOutOfRangeException(nameof(index));
+
+			data[index] = value.X;
+			data[Count + index] = value.Y;
+		}
+	}
+
+	/// <summary>Returns the underlying storage, the component spans laid out one after another.</summary>
+	internal Span<T> AsSpan()
+		=> data;
+	public Vector2DSoA<T> Clone()
+	{
+		Vector2DSoA<T> result = new(Count);
+		data.CopyTo(result.data, 0);
+		return result;
+	}
+	public void CopyTo(Span<Vector2D<T>> destination)
+	{
+		if (destination.Length < Count)
+			throw new ArgumentException("Destination is too short", nameof(destination));
+
+		ReadOnlySpan<T> x = X, y = Y;
+
+		for (int i = 0; i < Count; i++)
+			destination[i] = new(x[i], y[i]);
+	}
+	public Vector2D<T>[] ToArray()
+	{
+		Vector2D<T>[] result = new Vector2D<T>[Count];
+		CopyTo(result);
+		return result;
+	}
+
+	public static explicit operator Vector2DSoA<T>(Vector2D<T>[] vectors)
+		=> new(vectors);
+	public static explicit operator Vector2D<T>[](Vector2DSoA<T> vectors)
+		=> vectors.ToArray();
+}
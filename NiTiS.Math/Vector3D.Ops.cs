@@ -1,6 +1,7 @@
 ﻿using System;
 using System.Numerics;
 using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
@@ -24,6 +25,28 @@ public static class Vector3D
 	public static Vector3D<T> Add<T>(Vector3D<T> left, T right)
 		where T : unmanaged, INumberBase<T>
 		=> left + right;
+	/// <summary>Adds every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Add<T>(ReadOnlySpan<Vector3D<T>> left, ReadOnlySpan<Vector3D<T>> right, Span<Vector3D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Add(MemoryMarshal.Cast<Vector3D<T>, T>(left), MemoryMarshal.Cast<Vector3D<T>, T>(right), MemoryMarshal.Cast<Vector3D<T>, T>(destination));
+
+	/// <summary>Returns the unsigned angle between the vectors, in <c>[0, π]</c>.</summary>
+	public static Angle<T> AngleBetween<T>(Vector3D<T> from, Vector3D<T> to)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		// Kahan's formula stays accurate for nearly parallel and nearly opposite vectors, unlike acos of the dot product
+		Vector3D<T> a = from * Length(to);
+		Vector3D<T> b = to * Length(from);
+
+		return Angle<T>.FromRadians(Scalar<T>.Two * T.Atan2(Length(a - b), Length(a + b)));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool ApproximatelyEquals<T>(Vector3D<T> left, Vector3D<T> right, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> tolerance.AreEqual(left.X, right.X)
+		&& tolerance.AreEqual(left.Y, right.Y)
+		&& tolerance.AreEqual(left.Z, right.Z);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Bitwise<T>(Vector3D<T> operand)
@@ -34,6 +57,15 @@ public static class Vector3D
 			~operand.Z
 			);
 
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Ceiling<T>(Vector3D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Ceiling(operand.X),
+			T.Ceiling(operand.Y),
+			T.Ceiling(operand.Z)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Clamp<T>(Vector3D<T> value, Vector3D<T> min, Vector3D<T> max)
 		where T : unmanaged, INumberBase<T>, INumber<T>
@@ -43,6 +75,44 @@ public static class Vector3D
 			T.Clamp(value.Z, min.Z, max.Z)
 			);
 
+	/// <summary>Scales the vector down so its length does not exceed <paramref name="maxLength" />.</summary>
+	public static Vector3D<T> ClampLength<T>(Vector3D<T> vector, T maxLength)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T lengthSquared = vector.LengthSquared;
+
+		if (lengthSquared <= maxLength * maxLength)
+			return vector;
+
+		return vector * (maxLength / T.Sqrt(lengthSquared));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> CopySign<T>(Vector3D<T> value, Vector3D<T> sign)
+		where T : unmanaged, INumber<T>
+		=> new(
+			T.CopySign(value.X, sign.X),
+			T.CopySign(value.Y, sign.Y),
+			T.CopySign(value.Z, sign.Z)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> CopySign<T>(Vector3D<T> value, T sign)
+		where T : unmanaged, INumber<T>
+		=> new(
+			T.CopySign(value.X, sign),
+			T.CopySign(value.Y, sign),
+			T.CopySign(value.Z, sign)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Cos<T>(Vector3D<T> operand)
+		where T : unmanaged, ITrigonometricFunctions<T>
+		=> new(
+			T.Cos(operand.X),
+			T.Cos(operand.Y),
+			T.Cos(operand.Z)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Cross<T>(Vector3D<T> left, Vector3D<T> right)
 		where T : unmanaged, INumberBase<T>
@@ -85,7 +155,57 @@ public static class Vector3D
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T Dot<T>(Vector3D<T> left, Vector3D<T> right)
 		where T : unmanaged, INumberBase<T>
-		=> left.X * right.X + left.Y * right.Y + left.Z * right.Z;
+		=> Simd.IsAccelerated<T>()
+		? Simd.Dot(left, right)
+		: left.X * right.X + left.Y * right.Y + left.Z * right.Z;
+	/// <summary>Writes the dot product of every pair of vectors into <paramref name="destination" />.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector3DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void Dot<T>(ReadOnlySpan<Vector3D<T>> left, ReadOnlySpan<Vector3D<T>> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		SpanMath.ValidateBinary(left.Length, right.Length, destination.Length);
+
+		for (int i = 0; i < left.Length; i++)
+			destination[i] = Dot(left[i], right[i]);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Exp<T>(Vector3D<T> operand)
+		where T : unmanaged, IExponentialFunctions<T>
+		=> new(
+			T.Exp(operand.X),
+			T.Exp(operand.Y),
+			T.Exp(operand.Z)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Floor<T>(Vector3D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Floor(operand.X),
+			T.Floor(operand.Y),
+			T.Floor(operand.Z)
+			);
+
+	/// <summary>Returns <c>operand - Floor(operand)</c> for every component, the result is in <c>[0, 1]</c> as tiny negative components round up to <c>1</c>.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Fract<T>(Vector3D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Fract(operand.X),
+			Scalar.Fract(operand.Y),
+			Scalar.Fract(operand.Z)
+			);
+
+	/// <summary>Computes <c>(left * right) + addend</c> for every component, rounded as one ternary operation.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> FusedMultiplyAdd<T>(Vector3D<T> left, Vector3D<T> right, Vector3D<T> addend)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(
+			T.FusedMultiplyAdd(left.X, right.X, addend.X),
+			T.FusedMultiplyAdd(left.Y, right.Y, addend.Y),
+			T.FusedMultiplyAdd(left.Z, right.Z, addend.Z)
+			);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T Length<T>(Vector3D<T> operand)
@@ -95,7 +215,18 @@ public static class Vector3D
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Lerp<T>(Vector3D<T> left, Vector3D<T> right, T amount)
 		where T : unmanaged, INumberBase<T>
-		=> (left * (T.One - amount)) + (right * amount);
+		=> Simd.IsAccelerated<T>()
+		? Simd.Lerp(left, right, amount)
+		: (left * (T.One - amount)) + (right * amount);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Log<T>(Vector3D<T> operand)
+		where T : unmanaged, ILogarithmicFunctions<T>
+		=> new(
+			T.Log(operand.X),
+			T.Log(operand.Y),
+			T.Log(operand.Z)
+			);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Max<T>(Vector3D<T> left, Vector3D<T> right)
@@ -115,6 +246,37 @@ public static class Vector3D
 			T.Min(left.Z, right.Z)
 			);
 
+	/// <summary>Floored modulo, unlike <see cref="Rem{T}(Vector3D{T}, Vector3D{T})" /> the result has the sign of <paramref name="right" />, its magnitude may reach <paramref name="right" /> through rounding.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Mod<T>(Vector3D<T> left, Vector3D<T> right)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Mod(left.X, right.X),
+			Scalar.Mod(left.Y, right.Y),
+			Scalar.Mod(left.Z, right.Z)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Mod<T>(Vector3D<T> left, T right)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Mod(left.X, right),
+			Scalar.Mod(left.Y, right),
+			Scalar.Mod(left.Z, right)
+			);
+
+	/// <summary>Moves <paramref name="current" /> towards <paramref name="target" /> by at most <paramref name="maxDistance" />, without overshooting.</summary>
+	public static Vector3D<T> MoveTowards<T>(Vector3D<T> current, Vector3D<T> target, T maxDistance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Vector3D<T> difference = target - current;
+		T distance = Length(difference);
+
+		if (distance <= maxDistance || distance == T.Zero)
+			return target;
+
+		return current + difference * (maxDistance / distance);
+	}
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Multiply<T>(Vector3D<T> left, Vector3D<T> right)
 		where T : unmanaged, INumberBase<T>
@@ -131,6 +293,14 @@ public static class Vector3D
 			left * right.Y,
 			left * right.Z
 			);
+	/// <summary>Multiplies every pair of vectors component-wise into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(ReadOnlySpan<Vector3D<T>> left, ReadOnlySpan<Vector3D<T>> right, Span<Vector3D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Multiply(MemoryMarshal.Cast<Vector3D<T>, T>(left), MemoryMarshal.Cast<Vector3D<T>, T>(right), MemoryMarshal.Cast<Vector3D<T>, T>(destination));
+	/// <summary>Scales every vector into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(ReadOnlySpan<Vector3D<T>> left, T right, Span<Vector3D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Multiply(MemoryMarshal.Cast<Vector3D<T>, T>(left), right, MemoryMarshal.Cast<Vector3D<T>, T>(destination));
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Negate<T>(Vector3D<T> operand)
@@ -140,13 +310,218 @@ public static class Vector3D
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Normalize<T>(Vector3D<T> operand)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
-		=> operand / Length(operand);
+		=> Simd.IsAccelerated<T>()
+		? Simd.Normalize(operand)
+		: operand / Length(operand);
+	/// <summary>Normalizes every vector into <paramref name="destination" />, which may be the source span itself.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector3DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void Normalize<T>(ReadOnlySpan<Vector3D<T>> source, Span<Vector3D<T>> destination)
+		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
+	{
+		SpanMath.ValidateUnary(source.Length, destination.Length);
+
+		for (int i = 0; i < source.Length; i++)
+			destination[i] = Normalize(source[i]);
+	}
+
+	/// <summary>Normalizes <paramref name="normal" /> and makes <paramref name="tangent" /> a unit vector perpendicular to it (Gram-Schmidt).</summary>
+	public static void OrthoNormalize<T>(ref Vector3D<T> normal, ref Vector3D<T> tangent)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		normal = Normalize(normal);
+		tangent = Normalize(Reject(tangent, normal));
+	}
+	/// <inheritdoc cref="OrthoNormalize{T}(ref Vector3D{T}, ref Vector3D{T})" />
+	/// <remarks><paramref name="binormal" /> is made perpendicular to both other vectors.</remarks>
+	public static void OrthoNormalize<T>(ref Vector3D<T> normal, ref Vector3D<T> tangent, ref Vector3D<T> binormal)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		OrthoNormalize(ref normal, ref tangent);
+		binormal = Normalize(Reject(Reject(binormal, normal), tangent));
+	}
+
+	/// <summary>Returns a vector perpendicular to the operand, it is not normalized.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Perpendicular<T>(Vector3D<T> operand)
+		where T : unmanaged, INumber<T>
+		=> T.Abs(operand.X) > T.Abs(operand.Z)
+		? new(-operand.Y, operand.X, T.Zero)
+		: new(T.Zero, -operand.Z, operand.Y);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Pow<T>(Vector3D<T> value, Vector3D<T> exponent)
+		where T : unmanaged, IPowerFunctions<T>
+		=> new(
+			T.Pow(value.X, exponent.X),
+			T.Pow(value.Y, exponent.Y),
+			T.Pow(value.Z, exponent.Z)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Pow<T>(Vector3D<T> value, T exponent)
+		where T : unmanaged, IPowerFunctions<T>
+		=> new(
+			T.Pow(value.X, exponent),
+			T.Pow(value.Y, exponent),
+			T.Pow(value.Z, exponent)
+			);
+
+	/// <summary>Returns the component of <paramref name="vector" /> parallel to <paramref name="onto" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Project<T>(Vector3D<T> vector, Vector3D<T> onto)
+		where T : unmanaged, INumberBase<T>
+		=> onto * (Dot(vector, onto) / Dot(onto, onto));
+
+	/// <summary>Projects the vector onto the plane through the origin with the given normal.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> ProjectOnPlane<T>(Vector3D<T> vector, Vector3D<T> planeNormal)
+		where T : unmanaged, INumberBase<T>
+		=> Reject(vector, planeNormal);
 
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Reflect<T>(Vector3D<T> vector, Vector3D<T> normal)
+		where T : unmanaged, INumberBase<T>
+		=> vector - (Scalar<T>.Two * Dot(vector, normal)) * normal;
+	[Obsolete("Use the overload without the constant two")]
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Reflect<T>(Vector3D<T> vector, Vector3D<T> normal, T two)
 		where T : unmanaged, INumberBase<T>
 		=> vector - (two * Dot(vector, normal)) * normal;
 
+	/// <summary>Refracts the normalized <paramref name="incident" /> direction through a surface with the given normal.</summary>
+	/// <param name="eta">Ratio of the refractive indices, the one being left over the one being entered.</param>
+	/// <returns>The refracted direction, or zero on total internal reflection.</returns>
+	public static Vector3D<T> Refract<T>(Vector3D<T> incident, Vector3D<T> normal, T eta)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T cos = Dot(normal, incident);
+		T k = T.One - eta * eta * (T.One - cos * cos);
+
+		if (k < T.Zero)
+			return Vector3D<T>.Zero;
+
+		return incident * eta - normal * (eta * cos + T.Sqrt(k));
+	}
+
+	/// <summary>Returns the component of <paramref name="vector" /> perpendicular to <paramref name="onto" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Reject<T>(Vector3D<T> vector, Vector3D<T> onto)
+		where T : unmanaged, INumberBase<T>
+		=> vector - Project(vector, onto);
+
+	/// <summary>Truncated remainder, the result has the sign of <paramref name="left" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Rem<T>(Vector3D<T> left, Vector3D<T> right)
+		where T : unmanaged, INumber<T>
+		=> new(
+			left.X % right.X,
+			left.Y % right.Y,
+			left.Z % right.Z
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Rem<T>(Vector3D<T> left, T right)
+		where T : unmanaged, INumber<T>
+		=> new(
+			left.X % right,
+			left.Y % right,
+			left.Z % right
+			);
+
+	/// <summary>Rotates the <paramref name="from" /> direction towards <paramref name="to" /> by at most <paramref name="maxAngle" />, without overshooting.</summary>
+	public static Vector3D<T> RotateTowards<T>(Vector3D<T> from, Vector3D<T> to, Angle<T> maxAngle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Angle<T> angle = AngleBetween(from, to);
+
+		if (angle <= maxAngle)
+			return to;
+
+		return Slerp(from, to, maxAngle / angle);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Round<T>(Vector3D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Round(operand.X),
+			T.Round(operand.Y),
+			T.Round(operand.Z)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Round<T>(Vector3D<T> operand, MidpointRounding mode)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Round(operand.X, mode),
+			T.Round(operand.Y, mode),
+			T.Round(operand.Z, mode)
+			);
+
+	/// <summary>Returns <c>-1</c>, <c>0</c> or <c>1</c> for every component, NaN components stay NaN.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Sign<T>(Vector3D<T> operand)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Sign(operand.X),
+			Scalar.Sign(operand.Y),
+			Scalar.Sign(operand.Z)
+			);
+
+	/// <summary>Returns the angle from <paramref name="from" /> to <paramref name="to" />, positive when the rotation is counterclockwise around <paramref name="axis" />.</summary>
+	public static Angle<T> SignedAngle<T>(Vector3D<T> from, Vector3D<T> to, Vector3D<T> axis)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Angle<T> angle = AngleBetween(from, to);
+
+		return Dot(axis, Cross(from, to)) < T.Zero
+			? -angle
+			: angle;
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Sin<T>(Vector3D<T> operand)
+		where T : unmanaged, ITrigonometricFunctions<T>
+		=> new(
+			T.Sin(operand.X),
+			T.Sin(operand.Y),
+			T.Sin(operand.Z)
+			);
+
+	/// <summary>Spherically interpolates between two normalized directions at constant angular speed.</summary>
+	public static Vector3D<T> Slerp<T>(Vector3D<T> from, Vector3D<T> to, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T epsilon = T.CreateTruncating(1e-6);
+		T cos = T.Clamp(Dot(from, to), T.NegativeOne, T.One);
+
+		if (cos > T.One - epsilon)
+			return Normalize(Lerp(from, to, amount));
+
+		// Opposite directions have no unique arc, so any perpendicular one is taken
+		Vector3D<T> ortho = cos < epsilon - T.One
+			? Normalize<T>(Perpendicular(from))
+			: Normalize(to - from * cos);
+
+		T angle = T.Acos(cos) * amount;
+		return from * T.Cos(angle) + ortho * T.Sin(angle);
+	}
+
+	/// <summary>Hermite interpolation between <c>0</c> and <c>1</c> for every component of <paramref name="value" /> between the edges.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> SmoothStep<T>(Vector3D<T> edge0, Vector3D<T> edge1, Vector3D<T> value)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.SmoothStep(edge0.X, edge1.X, value.X),
+			Scalar.SmoothStep(edge0.Y, edge1.Y, value.Y),
+			Scalar.SmoothStep(edge0.Z, edge1.Z, value.Z)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> SmoothStep<T>(T edge0, T edge1, Vector3D<T> value)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.SmoothStep(edge0, edge1, value.X),
+			Scalar.SmoothStep(edge0, edge1, value.Y),
+			Scalar.SmoothStep(edge0, edge1, value.Z)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> SquareRoot<T>(Vector3D<T> operand)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -156,6 +531,24 @@ public static class Vector3D
 			T.Sqrt(operand.Z)
 			);
 
+	/// <summary>Returns <c>0</c> for every component of <paramref name="value" /> below <paramref name="edge" />, otherwise <c>1</c>.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Step<T>(Vector3D<T> edge, Vector3D<T> value)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Step(edge.X, value.X),
+			Scalar.Step(edge.Y, value.Y),
+			Scalar.Step(edge.Z, value.Z)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Step<T>(T edge, Vector3D<T> value)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Step(edge, value.X),
+			Scalar.Step(edge, value.Y),
+			Scalar.Step(edge, value.Z)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Substract<T>(Vector3D<T> left, Vector3D<T> right)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -164,4 +557,102 @@ public static class Vector3D
 			left.Y - right.Y,
 			left.Z - right.Z
 			);
+	/// <summary>Subtracts every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Substract<T>(ReadOnlySpan<Vector3D<T>> left, ReadOnlySpan<Vector3D<T>> right, Span<Vector3D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Subtract(MemoryMarshal.Cast<Vector3D<T>, T>(left), MemoryMarshal.Cast<Vector3D<T>, T>(right), MemoryMarshal.Cast<Vector3D<T>, T>(destination));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Transform<T>(Vector3D<T> vector, Mat3<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			vector.X * matrix.M11 + vector.Y * matrix.M21 + vector.Z * matrix.M31,
+			vector.X * matrix.M12 + vector.Y * matrix.M22 + vector.Z * matrix.M32,
+			vector.X * matrix.M13 + vector.Y * matrix.M23 + vector.Z * matrix.M33
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Transform<T>(Vector3D<T> position, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> Simd.IsAccelerated<T>()
+		? Simd.Transform(position, matrix)
+		: new(
+			position.X * matrix.M11 + position.Y * matrix.M21 + position.Z * matrix.M31 + matrix.M41,
+			position.X * matrix.M12 + position.Y * matrix.M22 + position.Z * matrix.M32 + matrix.M42,
+			position.X * matrix.M13 + position.Y * matrix.M23 + position.Z * matrix.M33 + matrix.M43
+			);
+	/// <param name="perspectiveDivide">Whether to divide the result by the transformed W component, as required for projection matrices.</param>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Transform<T>(Vector3D<T> position, Mat4<T> matrix, bool perspectiveDivide)
+		where T : unmanaged, INumberBase<T>
+	{
+		Vector3D<T> result = Transform(position, matrix);
+
+		if (!perspectiveDivide)
+			return result;
+
+		T w = position.X * matrix.M14 + position.Y * matrix.M24 + position.Z * matrix.M34 + matrix.M44;
+		return result / w;
+	}
+	/// <summary>Transforms the position by the given unit dual quaternion.</summary>
+	public static Vector3D<T> Transform<T>(Vector3D<T> position, DualQuaternion<T> transform)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> Transform(position, transform.Real) + DualQuaternion.GetTranslation(transform);
+	/// <summary>Rotates the vector by the given normalized quaternion.</summary>
+	public static Vector3D<T> Transform<T>(Vector3D<T> vector, Quaternion<T> rotation)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T x2 = rotation.X + rotation.X;
+		T y2 = rotation.Y + rotation.Y;
+		T z2 = rotation.Z + rotation.Z;
+
+		T wx2 = rotation.W * x2, wy2 = rotation.W * y2, wz2 = rotation.W * z2;
+		T xx2 = rotation.X * x2, xy2 = rotation.X * y2, xz2 = rotation.X * z2;
+		T yy2 = rotation.Y * y2, yz2 = rotation.Y * z2, zz2 = rotation.Z * z2;
+
+		return new(
+			vector.X * (T.One - yy2 - zz2) + vector.Y * (xy2 - wz2) + vector.Z * (xz2 + wy2),
+			vector.X * (xy2 + wz2) + vector.Y * (T.One - xx2 - zz2) + vector.Z * (yz2 - wx2),
+			vector.X * (xz2 - wy2) + vector.Y * (yz2 + wx2) + vector.Z * (T.One - xx2 - yy2)
+			);
+	}
+	/// <summary>Transforms every position into <paramref name="destination" />, which may be the source span itself.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector3DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void Transform<T>(ReadOnlySpan<Vector3D<T>> source, Mat4<T> matrix, Span<Vector3D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		SpanMath.ValidateUnary(source.Length, destination.Length);
+
+		for (int i = 0; i < source.Length; i++)
+			destination[i] = Transform(source[i], matrix);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> TransformNormal<T>(Vector3D<T> normal, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> Simd.IsAccelerated<T>()
+		? Simd.TransformNormal(normal, matrix)
+		: new(
+			normal.X * matrix.M11 + normal.Y * matrix.M21 + normal.Z * matrix.M31,
+			normal.X * matrix.M12 + normal.Y * matrix.M22 + normal.Z * matrix.M32,
+			normal.X * matrix.M13 + normal.Y * matrix.M23 + normal.Z * matrix.M33
+			);
+	/// <summary>Transforms every normal into <paramref name="destination" />, which may be the source span itself.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector3DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void TransformNormal<T>(ReadOnlySpan<Vector3D<T>> source, Mat4<T> matrix, Span<Vector3D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		SpanMath.ValidateUnary(source.Length, destination.Length);
+
+		for (int i = 0; i < source.Length; i++)
+			destination[i] = TransformNormal(source[i], matrix);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector3D<T> Truncate<T>(Vector3D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Truncate(operand.X),
+			T.Truncate(operand.Y),
+			T.Truncate(operand.Z)
+			);
 }
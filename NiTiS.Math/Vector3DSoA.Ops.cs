@@ -0,0 +1,182 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math;
+
+public static class Vector3DSoA
+{
+	/// <summary>Adds every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Add<T>(Vector3DSoA<T> left, Vector3DSoA<T> right, Vector3DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Add(left.AsSpan(), right.AsSpan(), destination.AsSpan());
+	}
+
+	/// <summary>Writes the dot product of every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Dot<T>(Vector3DSoA<T> left, Vector3DSoA<T> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		SpanMath.ValidateUnary(left.Count, destination.Length);
+
+		ReadOnlySpan<T> lx = left.X, ly = left.Y, lz = left.Z;
+		ReadOnlySpan<T> rx = right.X, ry = right.Y, rz = right.Z;
+
+		int i = 0;
+		if (SpanMath.IsVectorizable<T>())
+		{
+			for (int last = left.Count - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+			{
+				Vector<T> dot = new Vector<T>(lx[i..]) * new Vector<T>(rx[i..])
+					+ new Vector<T>(ly[i..]) * new Vector<T>(ry[i..])
+					+ new Vector<T>(lz[i..]) * new Vector<T>(rz[i..]);
+
+				dot.CopyTo(destination[i..]);
+			}
+		}
+
+		for (; i < left.Count; i++)
+			destination[i] = lx[i] * rx[i] + ly[i] * ry[i] + lz[i] * rz[i];
+	}
+
+	/// <summary>Multiplies every pair of vectors component-wise into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(Vector3DSoA<T> left, Vector3DSoA<T> right, Vector3DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Multiply(left.AsSpan(), right.AsSpan(), destination.AsSpan());
+	}
+	/// <summary>Scales every vector into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(Vector3DSoA<T> left, T right, Vector3DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Multiply(left.AsSpan(), right, destination.AsSpan());
+	}
+
+	/// <summary>Normalizes every vector into <paramref name="destination" />, which may be the source collection itself.</summary>
+	public static void Normalize<T>(Vector3DSoA<T> source, Vector3DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
+	{
+		ThrowIfCountMismatch(source, destination, nameof(destination));
+
+		ReadOnlySpan<T> sx = source.X, sy = source.Y, sz = source.Z;
+		Span<T> dx = destination.X, dy = destination.Y, dz = destination.Z;
+
+		int i = 0;
+		if (SpanMath.IsVectorizable<T>())
+		{
+			for (int last = source.Count - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+			{
+				Vector<T> x = new(sx[i..]);
+				Vector<T> y = new(sy[i..]);
+				Vector<T> z = new(sz[i..]);
+				Vector<T> length = Vector.SquareRoot(x * x + y * y + z * z);
+
+				(x / length).CopyTo(dx[i..]);
+				(y / length).CopyTo(dy[i..]);
+				(z / length).CopyTo(dz[i..]);
+			}
+		}
+
+		for (; i < source.Count; i++)
+		{
+			T length = T.Sqrt(sx[i] * sx[i] + sy[i] * sy[i] + sz[i] * sz[i]);
+
+			dx[i] = sx[i] / length;
+			dy[i] = sy[i] / length;
+			dz[i] = sz[i] / length;
+		}
+	}
+
+	/// <summary>Subtracts every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Substract<T>(Vector3DSoA<T> left, Vector3DSoA<T> right, Vector3DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Subtract(left.AsSpan(), right.AsSpan(), destination.AsSpan());
+	}
+
+	/// <summary>Transforms every position into <paramref name="destination" />, which may be the source collection itself.</summary>
+	public static void Transform<T>(Vector3DSoA<T> source, Mat4<T> matrix, Vector3DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(source, destination, nameof(destination));
+
+		ReadOnlySpan<T> sx = source.X, sy = source.Y, sz = source.Z;
+		Span<T> dx = destination.X, dy = destination.Y, dz = destination.Z;
+
+		int i = 0;
+		if (SpanMath.IsVectorizable<T>())
+		{
+			for (int last = source.Count - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+			{
+				Vector<T> x = new(sx[i..]);
+				Vector<T> y = new(sy[i..]);
+				Vector<T> z = new(sz[i..]);
+
+				(x * matrix.M11 + y * matrix.M21 + z * matrix.M31 + new Vector<T>(matrix.M41)).CopyTo(dx[i..]);
+				(x * matrix.M12 + y * matrix.M22 + z * matrix.M32 + new Vector<T>(matrix.M42)).CopyTo(dy[i..]);
+				(x * matrix.M13 + y * matrix.M23 + z * matrix.M33 + new Vector<T>(matrix.M43)).CopyTo(dz[i..]);
+			}
+		}
+
+		for (; i < source.Count; i++)
+		{
+			T x = sx[i], y = sy[i], z = sz[i];
+
+			dx[i] = x * matrix.M11 + y * matrix.M21 + z * matrix.M31 + matrix.M41;
+			dy[i] = x * matrix.M12 + y * matrix.M22 + z * matrix.M32 + matrix.M42;
+			dz[i] = x * matrix.M13 + y * matrix.M23 + z * matrix.M33 + matrix.M43;
+		}
+	}
+
+	/// <summary>Transforms every normal into <paramref name="destination" />, which may be the source collection itself.</summary>
+	public static void TransformNormal<T>(Vector3DSoA<T> source, Mat4<T> matrix, Vector3DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(source, destination, nameof(destination));
+
+		ReadOnlySpan<T> sx = source.X, sy = source.Y, sz = source.Z;
+		Span<T> dx = destination.X, dy = destination.Y, dz = destination.Z;
+
+		int i = 0;
+		if (SpanMath.IsVectorizable<T>())
+		{
+			for (int last = source.Count - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+			{
+				Vector<T> x = new(sx[i..]);
+				Vector<T> y = new(sy[i..]);
+				Vector<T> z = new(sz[i..]);
+
+				(x * matrix.M11 + y * matrix.M21 + z * matrix.M31).CopyTo(dx[i..]);
+				(x * matrix.M12 + y * matrix.M22 + z * matrix.M32).CopyTo(dy[i..]);
+				(x * matrix.M13 + y * matrix.M23 + z * matrix.M33).CopyTo(dz[i..]);
+			}
+		}
+
+		for (; i < source.Count; i++)
+		{
+			T x = sx[i], y = sy[i], z = sz[i];
+
+			dx[i] = x * matrix.M11 + y * matrix.M21 + z * matrix.M31;
+			dy[i] = x * matrix.M12 + y * matrix.M22 + z * matrix.M32;
+			dz[i] = x * matrix.M13 + y * matrix.M23 + z * matrix.M33;
+		}
+	}
+
+	private static void ThrowIfCountMismatch<T>(Vector3DSoA<T> left, Vector3DSoA<T> right, string paramName)
+		where T : unmanaged, INumberBase<T>
+	{
+		if (left.Count != right.Count)
+			throw new ArgumentException("Vector counts must be equal", paramName);
+	}
+}
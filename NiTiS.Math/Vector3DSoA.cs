@@ -0,0 +1,90 @@
+﻿using System;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Heap-backed collection of <see cref="Vector3D{T}" /> stored as a structure of arrays, every component is a contiguous span.
+/// </summary>
+public sealed class Vector3DSoA<T>
+	where T : unmanaged, INumberBase<T>
+{
+	private readonly T[] data;
+
+	public int Count { get; }
+	public Span<T> X => data.AsSpan(0, Count);
+	public Span<T> Y => data.AsSpan(Count, Count);
+	public Span<T> Z => data.AsSpan(Count * 2, Count);
+
+	public Vector3DSoA(int count)
+	{
+		if (count < 0)
+			throw new ArgumentOutOfRangeException(nameof(count));
+
+		Count = count;
+		data = new T[count * 3];
+	}
+	public Vector3DSoA(ReadOnlySpan<Vector3D<T>> vectors)
+		: this(vectors.Length)
+	{
+		Span<T> x = X, y = Y, z = Z;
+
+		for (int i = 0; i < vectors.Length; i++)
+			(x[i], y[i], z[i]) = (vectors[i].X, vectors[i].Y, vectors[i].Z);
+	}
+
+	public Vector3D<T> this[int index]
+	{
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		get
+		{
+			if ((uint)index >= (uint)Count)
+				throw new ArgumentOutOfRangeException(nameof(index));
+
+			return new(data[index], data[Count + index], data[Count * 2 + index]);
+		}
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		set
+		{
+			if ((uint)index >= (uint)Count)
+				throw new ArgumentOutOfRangeException(nameof(index));
+
+			data[index] = value.X;
+			data[Count + index] = value.Y;
+			data[Count * 2 + index] = value.Z;
+		}
+	}
+
+	/// <summary>Returns the underlying storage, the component spans laid out one after another.</summary>
+	internal Span<T> AsSpan()
+		=> data;
+	public Vector3DSoA<T> Clone()
+	{
+		Vector3DSoA<T> result = new(Count);
+		data.CopyTo(result.data, 0);
+		return result;
+	}
+	public void CopyTo(Span<Vector3D<T>> destination)
+	{
+		if (destination.Length < Count)
+			throw new ArgumentException("Destination is too short", nameof(destination));
+
+		ReadOnlySpan<T> x = X, y = Y, z = Z;
+
+		for (int i = 0; i < Count; i++)
+			destination[i] = new(x[i], y[i], z[i]);
+	}
+	public Vector3D<T>[] ToArray()
+	{
+		Vector3D<T>[] result = new Vector3D<T>[Count];
+		CopyTo(result);
+		return result;
+	}
+
+	public static explicit operator Vector3DSoA<T>(Vector3D<T>[] vectors)
+		=> new(vectors);
+	public static explicit operator Vector3D<T>[](Vector3DSoA<T> vectors)
+		=> vectors.ToArray();
+}
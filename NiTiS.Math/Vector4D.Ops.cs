@@ -1,6 +1,7 @@
 ﻿using System;
 using System.Numerics;
 using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
@@ -25,6 +26,29 @@ public static class Vector4D
 	public static Vector4D<T> Add<T>(Vector4D<T> left, T right)
 		where T : unmanaged, INumberBase<T>
 		=> left + right;
+	/// <summary>Adds every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Add<T>(ReadOnlySpan<Vector4D<T>> left, ReadOnlySpan<Vector4D<T>> right, Span<Vector4D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Add(MemoryMarshal.Cast<Vector4D<T>, T>(left), MemoryMarshal.Cast<Vector4D<T>, T>(right), MemoryMarshal.Cast<Vector4D<T>, T>(destination));
+
+	/// <summary>Returns the unsigned angle between the vectors, in <c>[0, π]</c>.</summary>
+	public static Angle<T> AngleBetween<T>(Vector4D<T> from, Vector4D<T> to)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		// Kahan's formula stays accurate for nearly parallel and nearly opposite vectors, unlike acos of the dot product
+		Vector4D<T> a = from * Length(to);
+		Vector4D<T> b = to * Length(from);
+
+		return Angle<T>.FromRadians(Scalar<T>.Two * T.Atan2(Length(a - b), Length(a + b)));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static bool ApproximatelyEquals<T>(Vector4D<T> left, Vector4D<T> right, Tolerance<T> tolerance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> tolerance.AreEqual(left.X, right.X)
+		&& tolerance.AreEqual(left.Y, right.Y)
+		&& tolerance.AreEqual(left.Z, right.Z)
+		&& tolerance.AreEqual(left.W, right.W);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Bitwise<T>(Vector4D<T> operand)
@@ -36,6 +60,16 @@ public static class Vector4D
 			~operand.W
 			);
 
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Ceiling<T>(Vector4D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Ceiling(operand.X),
+			T.Ceiling(operand.Y),
+			T.Ceiling(operand.Z),
+			T.Ceiling(operand.W)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Clamp<T>(Vector4D<T> value, Vector4D<T> min, Vector4D<T> max)
 		where T : unmanaged, INumberBase<T>, INumber<T>
@@ -46,6 +80,47 @@ public static class Vector4D
 			T.Clamp(value.W, min.W, max.W)
 			);
 
+	/// <summary>Scales the vector down so its length does not exceed <paramref name="maxLength" />.</summary>
+	public static Vector4D<T> ClampLength<T>(Vector4D<T> vector, T maxLength)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T lengthSquared = vector.LengthSquared;
+
+		if (lengthSquared <= maxLength * maxLength)
+			return vector;
+
+		return vector * (maxLength / T.Sqrt(lengthSquared));
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CopySign<T>(Vector4D<T> value, Vector4D<T> sign)
+		where T : unmanaged, INumber<T>
+		=> new(
+			T.CopySign(value.X, sign.X),
+			T.CopySign(value.Y, sign.Y),
+			T.CopySign(value.Z, sign.Z),
+			T.CopySign(value.W, sign.W)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CopySign<T>(Vector4D<T> value, T sign)
+		where T : unmanaged, INumber<T>
+		=> new(
+			T.CopySign(value.X, sign),
+			T.CopySign(value.Y, sign),
+			T.CopySign(value.Z, sign),
+			T.CopySign(value.W, sign)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Cos<T>(Vector4D<T> operand)
+		where T : unmanaged, ITrigonometricFunctions<T>
+		=> new(
+			T.Cos(operand.X),
+			T.Cos(operand.Y),
+			T.Cos(operand.Z),
+			T.Cos(operand.W)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T Distance<T>(Vector4D<T> from, Vector4D<T> to)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -80,7 +155,61 @@ public static class Vector4D
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T Dot<T>(Vector4D<T> left, Vector4D<T> right)
 		where T : unmanaged, INumberBase<T>
-		=> left.X * right.X + left.Y * right.Y + left.Z * right.Z + left.W * right.W;
+		=> Simd.IsAccelerated<T>()
+		? Simd.Dot(left, right)
+		: left.X * right.X + left.Y * right.Y + left.Z * right.Z + left.W * right.W;
+	/// <summary>Writes the dot product of every pair of vectors into <paramref name="destination" />.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector4DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void Dot<T>(ReadOnlySpan<Vector4D<T>> left, ReadOnlySpan<Vector4D<T>> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		SpanMath.ValidateBinary(left.Length, right.Length, destination.Length);
+
+		for (int i = 0; i < left.Length; i++)
+			destination[i] = Dot(left[i], right[i]);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Exp<T>(Vector4D<T> operand)
+		where T : unmanaged, IExponentialFunctions<T>
+		=> new(
+			T.Exp(operand.X),
+			T.Exp(operand.Y),
+			T.Exp(operand.Z),
+			T.Exp(operand.W)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Floor<T>(Vector4D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Floor(operand.X),
+			T.Floor(operand.Y),
+			T.Floor(operand.Z),
+			T.Floor(operand.W)
+			);
+
+	/// <summary>Returns <c>operand - Floor(operand)</c> for every component, the result is in <c>[0, 1]</c> as tiny negative components round up to <c>1</c>.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Fract<T>(Vector4D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Fract(operand.X),
+			Scalar.Fract(operand.Y),
+			Scalar.Fract(operand.Z),
+			Scalar.Fract(operand.W)
+			);
+
+	/// <summary>Computes <c>(left * right) + addend</c> for every component, rounded as one ternary operation.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> FusedMultiplyAdd<T>(Vector4D<T> left, Vector4D<T> right, Vector4D<T> addend)
+		where T : unmanaged, IFloatingPointIeee754<T>
+		=> new(
+			T.FusedMultiplyAdd(left.X, right.X, addend.X),
+			T.FusedMultiplyAdd(left.Y, right.Y, addend.Y),
+			T.FusedMultiplyAdd(left.Z, right.Z, addend.Z),
+			T.FusedMultiplyAdd(left.W, right.W, addend.W)
+			);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static T Length<T>(Vector4D<T> operand)
@@ -90,7 +219,23 @@ public static class Vector4D
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector3D<T> Lerp<T>(Vector3D<T> left, Vector3D<T> right, T amount)
 		where T : unmanaged, INumberBase<T>
-		=> (left * (T.One - amount)) + (right * amount);
+		=> Vector3D.Lerp(left, right, amount);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Lerp<T>(Vector4D<T> left, Vector4D<T> right, T amount)
+		where T : unmanaged, INumberBase<T>
+		=> Simd.IsAccelerated<T>()
+		? Simd.Lerp(left, right, amount)
+		: (left * (T.One - amount)) + (right * amount);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Log<T>(Vector4D<T> operand)
+		where T : unmanaged, ILogarithmicFunctions<T>
+		=> new(
+			T.Log(operand.X),
+			T.Log(operand.Y),
+			T.Log(operand.Z),
+			T.Log(operand.W)
+			);
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Max<T>(Vector4D<T> left, Vector4D<T> right)
@@ -112,6 +257,39 @@ public static class Vector4D
 			T.Min(left.W, right.W)
 			);
 
+	/// <summary>Floored modulo, unlike <see cref="Rem{T}(Vector4D{T}, Vector4D{T})" /> the result has the sign of <paramref name="right" />, its magnitude may reach <paramref name="right" /> through rounding.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Mod<T>(Vector4D<T> left, Vector4D<T> right)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Mod(left.X, right.X),
+			Scalar.Mod(left.Y, right.Y),
+			Scalar.Mod(left.Z, right.Z),
+			Scalar.Mod(left.W, right.W)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Mod<T>(Vector4D<T> left, T right)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.Mod(left.X, right),
+			Scalar.Mod(left.Y, right),
+			Scalar.Mod(left.Z, right),
+			Scalar.Mod(left.W, right)
+			);
+
+	/// <summary>Moves <paramref name="current" /> towards <paramref name="target" /> by at most <paramref name="maxDistance" />, without overshooting.</summary>
+	public static Vector4D<T> MoveTowards<T>(Vector4D<T> current, Vector4D<T> target, T maxDistance)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Vector4D<T> difference = target - current;
+		T distance = Length(difference);
+
+		if (distance <= maxDistance || distance == T.Zero)
+			return target;
+
+		return current + difference * (maxDistance / distance);
+	}
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Multiply<T>(Vector4D<T> left, Vector4D<T> right)
 		where T : unmanaged, INumberBase<T>
@@ -129,6 +307,14 @@ public static class Vector4D
 			left * right.Z,
 			left * right.W
 			);
+	/// <summary>Multiplies every pair of vectors component-wise into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(ReadOnlySpan<Vector4D<T>> left, ReadOnlySpan<Vector4D<T>> right, Span<Vector4D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Multiply(MemoryMarshal.Cast<Vector4D<T>, T>(left), MemoryMarshal.Cast<Vector4D<T>, T>(right), MemoryMarshal.Cast<Vector4D<T>, T>(destination));
+	/// <summary>Scales every vector into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(ReadOnlySpan<Vector4D<T>> left, T right, Span<Vector4D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Multiply(MemoryMarshal.Cast<Vector4D<T>, T>(left), right, MemoryMarshal.Cast<Vector4D<T>, T>(destination));
 
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Negate<T>(Vector4D<T> operand)
@@ -138,13 +324,201 @@ public static class Vector4D
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Normalize<T>(Vector4D<T> operand)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
-		=> operand / Length(operand);
+		=> Simd.IsAccelerated<T>()
+		? Simd.Normalize(operand)
+		: operand / Length(operand);
+	/// <summary>Normalizes every vector into <paramref name="destination" />, which may be the source span itself.</summary>
+	/// <remarks>Every vector takes the single vector path, use <see cref="Vector4DSoA{T}" /> for SIMD across vectors.</remarks>
+	public static void Normalize<T>(ReadOnlySpan<Vector4D<T>> source, Span<Vector4D<T>> destination)
+		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
+	{
+		SpanMath.ValidateUnary(source.Length, destination.Length);
+
+		for (int i = 0; i < source.Length; i++)
+			destination[i] = Normalize(source[i]);
+	}
 
+	/// <summary>Normalizes <paramref name="normal" /> and makes <paramref name="tangent" /> a unit vector perpendicular to it (Gram-Schmidt).</summary>
+	public static void OrthoNormalize<T>(ref Vector4D<T> normal, ref Vector4D<T> tangent)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		normal = Normalize(normal);
+		tangent = Normalize(Reject(tangent, normal));
+	}
+
+	/// <summary>Returns a vector perpendicular to the operand, it is not normalized.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Perpendicular<T>(Vector4D<T> operand)
+		where T : unmanaged, INumberBase<T>
+		=> new(-operand.Y, operand.X, -operand.W, operand.Z);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Pow<T>(Vector4D<T> value, Vector4D<T> exponent)
+		where T : unmanaged, IPowerFunctions<T>
+		=> new(
+			T.Pow(value.X, exponent.X),
+			T.Pow(value.Y, exponent.Y),
+			T.Pow(value.Z, exponent.Z),
+			T.Pow(value.W, exponent.W)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Pow<T>(Vector4D<T> value, T exponent)
+		where T : unmanaged, IPowerFunctions<T>
+		=> new(
+			T.Pow(value.X, exponent),
+			T.Pow(value.Y, exponent),
+			T.Pow(value.Z, exponent),
+			T.Pow(value.W, exponent)
+			);
+
+	/// <summary>Returns the component of <paramref name="vector" /> parallel to <paramref name="onto" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Project<T>(Vector4D<T> vector, Vector4D<T> onto)
+		where T : unmanaged, INumberBase<T>
+		=> onto * (Dot(vector, onto) / Dot(onto, onto));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Reflect<T>(Vector4D<T> vector, Vector4D<T> normal)
+		where T : unmanaged, INumberBase<T>
+		=> vector - (Scalar<T>.Two * Dot(vector, normal)) * normal;
+	[Obsolete("Use the overload without the constant two")]
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Reflect<T>(Vector4D<T> vector, Vector4D<T> normal, T two)
 		where T : unmanaged, INumberBase<T>
 		=> vector - (two * Dot(vector, normal)) * normal;
 
+	/// <summary>Refracts the normalized <paramref name="incident" /> direction through a surface with the given normal.</summary>
+	/// <param name="eta">Ratio of the refractive indices, the one being left over the one being entered.</param>
+	/// <returns>The refracted direction, or zero on total internal reflection.</returns>
+	public static Vector4D<T> Refract<T>(Vector4D<T> incident, Vector4D<T> normal, T eta)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T cos = Dot(normal, incident);
+		T k = T.One - eta * eta * (T.One - cos * cos);
+
+		if (k < T.Zero)
+			return Vector4D<T>.Zero;
+
+		return incident * eta - normal * (eta * cos + T.Sqrt(k));
+	}
+
+	/// <summary>Returns the component of <paramref name="vector" /> perpendicular to <paramref name="onto" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Reject<T>(Vector4D<T> vector, Vector4D<T> onto)
+		where T : unmanaged, INumberBase<T>
+		=> vector - Project(vector, onto);
+
+	/// <summary>Truncated remainder, the result has the sign of <paramref name="left" />.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Rem<T>(Vector4D<T> left, Vector4D<T> right)
+		where T : unmanaged, INumber<T>
+		=> new(
+			left.X % right.X,
+			left.Y % right.Y,
+			left.Z % right.Z,
+			left.W % right.W
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Rem<T>(Vector4D<T> left, T right)
+		where T : unmanaged, INumber<T>
+		=> new(
+			left.X % right,
+			left.Y % right,
+			left.Z % right,
+			left.W % right
+			);
+
+	/// <summary>Rotates the <paramref name="from" /> direction towards <paramref name="to" /> by at most <paramref name="maxAngle" />, without overshooting.</summary>
+	public static Vector4D<T> RotateTowards<T>(Vector4D<T> from, Vector4D<T> to, Angle<T> maxAngle)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		Angle<T> angle = AngleBetween(from, to);
+
+		if (angle <= maxAngle)
+			return to;
+
+		return Slerp(from, to, maxAngle / angle);
+	}
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Round<T>(Vector4D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Round(operand.X),
+			T.Round(operand.Y),
+			T.Round(operand.Z),
+			T.Round(operand.W)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Round<T>(Vector4D<T> operand, MidpointRounding mode)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Round(operand.X, mode),
+			T.Round(operand.Y, mode),
+			T.Round(operand.Z, mode),
+			T.Round(operand.W, mode)
+			);
+
+	/// <summary>Returns <c>-1</c>, <c>0</c> or <c>1</c> for every component, NaN components stay NaN.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Sign<T>(Vector4D<T> operand)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Sign(operand.X),
+			Scalar.Sign(operand.Y),
+			Scalar.Sign(operand.Z),
+			Scalar.Sign(operand.W)
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Sin<T>(Vector4D<T> operand)
+		where T : unmanaged, ITrigonometricFunctions<T>
+		=> new(
+			T.Sin(operand.X),
+			T.Sin(operand.Y),
+			T.Sin(operand.Z),
+			T.Sin(operand.W)
+			);
+
+	/// <summary>Spherically interpolates between two normalized directions at constant angular speed.</summary>
+	public static Vector4D<T> Slerp<T>(Vector4D<T> from, Vector4D<T> to, T amount)
+		where T : unmanaged, IFloatingPointIeee754<T>
+	{
+		T epsilon = T.CreateTruncating(1e-6);
+		T cos = T.Clamp(Dot(from, to), T.NegativeOne, T.One);
+
+		if (cos > T.One - epsilon)
+			return Normalize(Lerp(from, to, amount));
+
+		// Opposite directions have no unique arc, so any perpendicular one is taken
+		Vector4D<T> ortho = cos < epsilon - T.One
+			? Normalize(Perpendicular(from))
+			: Normalize(to - from * cos);
+
+		T angle = T.Acos(cos) * amount;
+		return from * T.Cos(angle) + ortho * T.Sin(angle);
+	}
+
+	/// <summary>Hermite interpolation between <c>0</c> and <c>1</c> for every component of <paramref name="value" /> between the edges.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> SmoothStep<T>(Vector4D<T> edge0, Vector4D<T> edge1, Vector4D<T> value)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.SmoothStep(edge0.X, edge1.X, value.X),
+			Scalar.SmoothStep(edge0.Y, edge1.Y, value.Y),
+			Scalar.SmoothStep(edge0.Z, edge1.Z, value.Z),
+			Scalar.SmoothStep(edge0.W, edge1.W, value.W)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> SmoothStep<T>(T edge0, T edge1, Vector4D<T> value)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			Scalar.SmoothStep(edge0, edge1, value.X),
+			Scalar.SmoothStep(edge0, edge1, value.Y),
+			Scalar.SmoothStep(edge0, edge1, value.Z),
+			Scalar.SmoothStep(edge0, edge1, value.W)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> SquareRoot<T>(Vector4D<T> operand)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -155,6 +529,26 @@ public static class Vector4D
 			T.Sqrt(operand.W)
 			);
 
+	/// <summary>Returns <c>0</c> for every component of <paramref name="value" /> below <paramref name="edge" />, otherwise <c>1</c>.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Step<T>(Vector4D<T> edge, Vector4D<T> value)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Step(edge.X, value.X),
+			Scalar.Step(edge.Y, value.Y),
+			Scalar.Step(edge.Z, value.Z),
+			Scalar.Step(edge.W, value.W)
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Step<T>(T edge, Vector4D<T> value)
+		where T : unmanaged, INumber<T>
+		=> new(
+			Scalar.Step(edge, value.X),
+			Scalar.Step(edge, value.Y),
+			Scalar.Step(edge, value.Z),
+			Scalar.Step(edge, value.W)
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> Substract<T>(Vector4D<T> left, Vector4D<T> right)
 		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
@@ -164,4 +558,46 @@ public static class Vector4D
 			left.Z - right.Z,
 			left.W - right.W
 			);
+	/// <summary>Subtracts every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Substract<T>(ReadOnlySpan<Vector4D<T>> left, ReadOnlySpan<Vector4D<T>> right, Span<Vector4D<T>> destination)
+		where T : unmanaged, INumberBase<T>
+		=> SpanMath.Subtract(MemoryMarshal.Cast<Vector4D<T>, T>(left), MemoryMarshal.Cast<Vector4D<T>, T>(right), MemoryMarshal.Cast<Vector4D<T>, T>(destination));
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Transform<T>(Vector2D<T> position, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			position.X * matrix.M11 + position.Y * matrix.M21 + matrix.M41,
+			position.X * matrix.M12 + position.Y * matrix.M22 + matrix.M42,
+			position.X * matrix.M13 + position.Y * matrix.M23 + matrix.M43,
+			position.X * matrix.M14 + position.Y * matrix.M24 + matrix.M44
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Transform<T>(Vector3D<T> position, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			position.X * matrix.M11 + position.Y * matrix.M21 + position.Z * matrix.M31 + matrix.M41,
+			position.X * matrix.M12 + position.Y * matrix.M22 + position.Z * matrix.M32 + matrix.M42,
+			position.X * matrix.M13 + position.Y * matrix.M23 + position.Z * matrix.M33 + matrix.M43,
+			position.X * matrix.M14 + position.Y * matrix.M24 + position.Z * matrix.M34 + matrix.M44
+			);
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Transform<T>(Vector4D<T> vector, Mat4<T> matrix)
+		where T : unmanaged, INumberBase<T>
+		=> new(
+			vector.X * matrix.M11 + vector.Y * matrix.M21 + vector.Z * matrix.M31 + vector.W * matrix.M41,
+			vector.X * matrix.M12 + vector.Y * matrix.M22 + vector.Z * matrix.M32 + vector.W * matrix.M42,
+			vector.X * matrix.M13 + vector.Y * matrix.M23 + vector.Z * matrix.M33 + vector.W * matrix.M43,
+			vector.X * matrix.M14 + vector.Y * matrix.M24 + vector.Z * matrix.M34 + vector.W * matrix.M44
+			);
+
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> Truncate<T>(Vector4D<T> operand)
+		where T : unmanaged, IFloatingPoint<T>
+		=> new(
+			T.Truncate(operand.X),
+			T.Truncate(operand.Y),
+			T.Truncate(operand.Z),
+			T.Truncate(operand.W)
+			);
 }
@@ -6,13 +6,12 @@ using System.Globalization;
 using System.Numerics;
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
-using System.Text;
 using static System.Runtime.CompilerServices.MethodImplOptions;
 
 namespace NiTiS.Math;
 
 [DebuggerDisplay($@"{{{nameof(ToString)}(""G""),nq}}")]
-public readonly unsafe struct Vector4D<T> :
+public readonly unsafe partial struct Vector4D<T> :
 	// Vector op Vector
 	IAdditionOperators<Vector4D<T>, Vector4D<T>, Vector4D<T>>,
 	ISubtractionOperators<Vector4D<T>, Vector4D<T>, Vector4D<T>>,
@@ -27,7 +26,11 @@ public readonly unsafe struct Vector4D<T> :
 	// Unary op
 	IUnaryNegationOperators<Vector4D<T>, Vector4D<T>>,
 	IUnaryPlusOperators<Vector4D<T>, Vector4D<T>>,
-	IFormattable,
+	ISpanFormattable,
+#if NET8_0_OR_GREATER
+	IUtf8SpanFormattable,
+#endif
+	ISpanParsable<Vector4D<T>>,
 	IEquatable<Vector4D<T>>,
 	// Cast op
 	IImplicitCastOperators<Vector4D<T>, Vector3D<T>>,
@@ -91,6 +94,57 @@ public readonly unsafe struct Vector4D<T> :
 	public static Vector4D<T> UnitZ => new(T.Zero, T.Zero, T.One, T.Zero);
 	public static Vector4D<T> UnitW => new(T.Zero, T.Zero, T.Zero, T.One);
 
+	/// <summary>Converts every component, throwing <see cref="OverflowException" /> when it is not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CreateChecked<TOther>(Vector4D<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(T.CreateChecked(value.X), T.CreateChecked(value.Y), T.CreateChecked(value.Z), T.CreateChecked(value.W));
+	/// <summary>Converts every component, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CreateSaturating<TOther>(Vector4D<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(T.CreateSaturating(value.X), T.CreateSaturating(value.Y), T.CreateSaturating(value.Z), T.CreateSaturating(value.W));
+	/// <summary>Converts every component, truncating values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CreateTruncating<TOther>(Vector4D<TOther> value)
+		where TOther : unmanaged, INumberBase<TOther>
+		=> new(T.CreateTruncating(value.X), T.CreateTruncating(value.Y), T.CreateTruncating(value.Z), T.CreateTruncating(value.W));
+	/// <summary>Rounds every component up and converts it, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CreateCeiling<TOther>(Vector4D<TOther> value)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> new(
+			T.CreateSaturating(TOther.Ceiling(value.X)),
+			T.CreateSaturating(TOther.Ceiling(value.Y)),
+			T.CreateSaturating(TOther.Ceiling(value.Z)),
+			T.CreateSaturating(TOther.Ceiling(value.W))
+			);
+	/// <summary>Rounds every component down and converts it, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CreateFloor<TOther>(Vector4D<TOther> value)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> new(
+			T.CreateSaturating(TOther.Floor(value.X)),
+			T.CreateSaturating(TOther.Floor(value.Y)),
+			T.CreateSaturating(TOther.Floor(value.Z)),
+			T.CreateSaturating(TOther.Floor(value.W))
+			);
+	/// <summary>Rounds every component to the nearest integer and converts it, clamping values that are not representable.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CreateRounded<TOther>(Vector4D<TOther> value)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> CreateRounded(value, MidpointRounding.ToEven);
+	/// <inheritdoc cref="CreateRounded{TOther}(Vector4D{TOther})" />
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public static Vector4D<T> CreateRounded<TOther>(Vector4D<TOther> value, MidpointRounding mode)
+		where TOther : unmanaged, IFloatingPoint<TOther>
+		=> new(
+			T.CreateSaturating(TOther.Round(value.X, mode)),
+			T.CreateSaturating(TOther.Round(value.Y, mode)),
+			T.CreateSaturating(TOther.Round(value.Z, mode)),
+			T.CreateSaturating(TOther.Round(value.W, mode))
+			);
+
 	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
 	public static Vector4D<T> operator +(Vector4D<T> left, Vector4D<T> right)
 		=> new(
@@ -217,6 +271,11 @@ public readonly unsafe struct Vector4D<T> :
 			operand.Z
 			);
 
+	/// <summary>Returns a copy of the vector with the specified components replaced.</summary>
+	[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+	public readonly Vector4D<T> With(T? x = null, T? y = null, T? z = null, T? w = null)
+		=> new(x ?? X, y ?? Y, z ?? Z, w ?? W);
+
 	public readonly void CopyTo(T[] array)
 		=> CopyTo(array, 0);
 	public readonly void CopyTo(T[] array, uint offset)
@@ -250,29 +309,51 @@ public readonly unsafe struct Vector4D<T> :
 	public override readonly string ToString() => ToString("G", CultureInfo.CurrentCulture);
 	public readonly string ToString(string? format) => ToString(format, CultureInfo.CurrentCulture);
 	public readonly string ToString(string? format, IFormatProvider? formatProvider)
-	{
-		StringBuilder sb = new();
-		string separator = NumberFormatInfo.GetInstance(formatProvider).NumberGroupSeparator;
-		sb.Append('<');
-
-		sb.Append(X.ToString(format, formatProvider));
-		sb.Append(separator);
-		if (!string.IsNullOrWhiteSpace(separator))
-			sb.Append(' ');
+		=> Formatting.ToString(this, format, formatProvider);
+	public readonly bool TryFormat(Span<char> destination, out int charsWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatVector(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in X), ElementCount), destination, out charsWritten, format, provider);
+#if NET8_0_OR_GREATER
+	public readonly bool TryFormat(Span<byte> utf8Destination, out int bytesWritten, ReadOnlySpan<char> format, IFormatProvider? provider)
+		=> Formatting.TryFormatVector(MemoryMarshal.CreateReadOnlySpan(ref Unsafe.AsRef(in X), ElementCount), utf8Destination, out bytesWritten, format, provider);
+#endif
 
-		sb.Append(Y.ToString(format, formatProvider));
-		sb.Append(separator);
-		if (!string.IsNullOrWhiteSpace(separator))
-			sb.Append(' ');
+	public static Vector4D<T> Parse(string s)
+		=> Parse(s, CultureInfo.CurrentCulture);
+	/// <summary>Parses a vector written by <see cref="ToString(string?, IFormatProvider?)" /> with the same provider, <c>(x, y)</c> and whitespace separated forms are accepted too.</summary>
+	/// <exception cref="FormatException"><paramref name="s" /> is not a vector.</exception>
+	public static Vector4D<T> Parse(string s, IFormatProvider? provider)
+	{
+		ArgumentNullException.ThrowIfNull(s);
+		return Parse(s.AsSpan(), provider);
+	}
+	/// <inheritdoc cref="Parse(string, IFormatProvider?)" />
+	public static Vector4D<T> Parse(ReadOnlySpan<char> s, IFormatProvider? provider)
+		=> TryParse(s, provider, out Vector4D<T> result)
+		? result
+		: throw new FormatException("Input string was not in a correct format");
+	public static bool TryParse([NotNullWhen(true)] string? s, out Vector4D<T> result)
+		=> TryParse(s, CultureInfo.CurrentCulture, out result);
+	public static bool TryParse([NotNullWhen(true)] string? s, IFormatProvider? provider, out Vector4D<T> result)
+	{
+		if (s is null)
+		{
+			result = default;
+			return false;
+		}
 
-		sb.Append(Z.ToString(format, formatProvider));
-		sb.Append(separator);
-		if (!string.IsNullOrWhiteSpace(separator))
-			sb.Append(' ');
+		return TryParse(s.AsSpan(), provider, out result);
+	}
+	public static bool TryParse(ReadOnlySpan<char> s, IFormatProvider? provider, out Vector4D<T> result)
+	{
+		Span<T> components = stackalloc T[ElementCount];
 
-		sb.Append(W.ToString(format, formatProvider));
+		if (!VectorParser.TryParse(s, provider, components))
+		{
+			result = default;
+			return false;
+		}
 
-		sb.Append('>');
-		return sb.ToString();
+		result = new(components);
+		return true;
 	}
 }
\ No newline at end of file
@@ -0,0 +1,118 @@
+﻿using System;
+using System.Numerics;
+
+namespace NiTiS.Math;
+
+public static class Vector4DSoA
+{
+	/// <summary>Adds every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Add<T>(Vector4DSoA<T> left, Vector4DSoA<T> right, Vector4DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Add(left.AsSpan(), right.AsSpan(), destination.AsSpan());
+	}
+
+	/// <summary>Writes the dot product of every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Dot<T>(Vector4DSoA<T> left, Vector4DSoA<T> right, Span<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		SpanMath.ValidateUnary(left.Count, destination.Length);
+
+		ReadOnlySpan<T> lx = left.X, ly = left.Y, lz = left.Z, lw = left.W;
+		ReadOnlySpan<T> rx = right.X, ry = right.Y, rz = right.Z, rw = right.W;
+
+		int i = 0;
+		if (SpanMath.IsVectorizable<T>())
+		{
+			for (int last = left.Count - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+			{
+				Vector<T> dot = new Vector<T>(lx[i..]) * new Vector<T>(rx[i..])
+					+ new Vector<T>(ly[i..]) * new Vector<T>(ry[i..])
+					+ new Vector<T>(lz[i..]) * new Vector<T>(rz[i..])
+					+ new Vector<T>(lw[i..]) * new Vector<T>(rw[i..]);
+
+				dot.CopyTo(destination[i..]);
+			}
+		}
+
+		for (; i < left.Count; i++)
+			destination[i] = lx[i] * rx[i] + ly[i] * ry[i] + lz[i] * rz[i] + lw[i] * rw[i];
+	}
+
+	/// <summary>Multiplies every pair of vectors component-wise into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(Vector4DSoA<T> left, Vector4DSoA<T> right, Vector4DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Multiply(left.AsSpan(), right.AsSpan(), destination.AsSpan());
+	}
+	/// <summary>Scales every vector into <paramref name="destination" />.</summary>
+	public static void Multiply<T>(Vector4DSoA<T> left, T right, Vector4DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Multiply(left.AsSpan(), right, destination.AsSpan());
+	}
+
+	/// <summary>Normalizes every vector into <paramref name="destination" />, which may be the source collection itself.</summary>
+	public static void Normalize<T>(Vector4DSoA<T> source, Vector4DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>, IRootFunctions<T>
+	{
+		ThrowIfCountMismatch(source, destination, nameof(destination));
+
+		ReadOnlySpan<T> sx = source.X, sy = source.Y, sz = source.Z, sw = source.W;
+		Span<T> dx = destination.X, dy = destination.Y, dz = destination.Z, dw = destination.W;
+
+		int i = 0;
+		if (SpanMath.IsVectorizable<T>())
+		{
+			for (int last = source.Count - Vector<T>.Count; i <= last; i += Vector<T>.Count)
+			{
+				Vector<T> x = new(sx[i..]);
+				Vector<T> y = new(sy[i..]);
+				Vector<T> z = new(sz[i..]);
+				Vector<T> w = new(sw[i..]);
+				Vector<T> length = Vector.SquareRoot(x * x + y * y + z * z + w * w);
+
+				(x / length).CopyTo(dx[i..]);
+				(y / length).CopyTo(dy[i..]);
+				(z / length).CopyTo(dz[i..]);
+				(w / length).CopyTo(dw[i..]);
+			}
+		}
+
+		for (; i < source.Count; i++)
+		{
+			T length = T.Sqrt(sx[i] * sx[i] + sy[i] * sy[i] + sz[i] * sz[i] + sw[i] * sw[i]);
+
+			dx[i] = sx[i] / length;
+			dy[i] = sy[i] / length;
+			dz[i] = sz[i] / length;
+			dw[i] = sw[i] / length;
+		}
+	}
+
+	/// <summary>Subtracts every pair of vectors into <paramref name="destination" />.</summary>
+	public static void Substract<T>(Vector4DSoA<T> left, Vector4DSoA<T> right, Vector4DSoA<T> destination)
+		where T : unmanaged, INumberBase<T>
+	{
+		ThrowIfCountMismatch(left, right, nameof(right));
+		ThrowIfCountMismatch(left, destination, nameof(destination));
+
+		SpanMath.Subtract(left.AsSpan(), right.AsSpan(), destination.AsSpan());
+	}
+
+	private static void ThrowIfCountMismatch<T>(Vector4DSoA<T> left, Vector4DSoA<T> right, string paramName)
+		where T : unmanaged, INumberBase<T>
+	{
+		if (left.Count != right.Count)
+			throw new ArgumentException("Vector counts must be equal", paramName);
+	}
+}
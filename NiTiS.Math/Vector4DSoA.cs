@@ -0,0 +1,92 @@
+﻿using System;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using static System.Runtime.CompilerServices.MethodImplOptions;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Heap-backed collection of <see cref="Vector4D{T}" /> stored as a structure of arrays, every component is a contiguous span.
+/// </summary>
+public sealed class Vector4DSoA<T>
+	where T : unmanaged, INumberBase<T>
+{
+	private readonly T[] data;
+
+	public int Count { get; }
+	public Span<T> X => data.AsSpan(0, Count);
+	public Span<T> Y => data.AsSpan(Count, Count);
+	public Span<T> Z => data.AsSpan(Count * 2, Count);
+	public Span<T> W => data.AsSpan(Count * 3, Count);
+
+	public Vector4DSoA(int count)
+	{
+		if (count < 0)
+			throw new ArgumentOutOfRangeException(nameof(count));
+
+		Count = count;
+		data = new T[count * 4];
+	}
+	public Vector4DSoA(ReadOnlySpan<Vector4D<T>> vectors)
+		: this(vectors.Length)
+	{
+		Span<T> x = X, y = Y, z = Z, w = W;
+
+		for (int i = 0; i < vectors.Length; i++)
+			(x[i], y[i], z[i], w[i]) = (vectors[i].X, vectors[i].Y, vectors[i].Z, vectors[i].W);
+	}
+
+	public Vector4D<T> this[int index]
+	{
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		get
+		{
+			if ((uint)index >= (uint)Count)
+				throw new ArgumentOutOfRangeException(nameof(index));
+
+			return new(data[index], data[Count + index], data[Count * 2 + index], data[Count * 3 + index]);
+		}
+		[MethodImpl(AggressiveInlining | AggressiveOptimization)]
+		set
+		{
+			if ((uint)index >= (uint)Count)
+				throw new ArgumentOutOfRangeException(nameof(index));
+
+			data[index] = value.X;
+			data[Count + index] = value.Y;
+			data[Count * 2 + index] = value.Z;
+			data[Count * 3 + index] = value.W;
+		}
+	}
+
+	/// <summary>Returns the underlying storage, the component spans laid out one after another.</summary>
+	internal Span<T> AsSpan()
+		=> data;
+	public Vector4DSoA<T> Clone()
+	{
+		Vector4DSoA<T> result = new(Count);
+		data.CopyTo(result.data, 0);
+		return result;
+	}
+	public void CopyTo(Span<Vector4D<T>> destination)
+	{
+		if (destination.Length < Count)
+			throw new ArgumentException("Destination is too short", nameof(destination));
+
+		ReadOnlySpan<T> x = X, y = Y, z = Z, w = W;
+
+		for (int i = 0; i < Count; i++)
+			destination[i] = new(x[i], y[i], z[i], w[i]);
+	}
+	public Vector4D<T>[] ToArray()
+	{
+		Vector4D<T>[] result = new Vector4D<T>[Count];
+		CopyTo(result);
+		return result;
+	}
+
+	public static explicit operator Vector4DSoA<T>(Vector4D<T>[] vectors)
+		=> new(vectors);
+	public static explicit operator Vector4D<T>[](Vector4DSoA<T> vectors)
+		=> vectors.ToArray();
+}
@@ -0,0 +1,89 @@
+﻿using System;
+using System.Globalization;
+using System.Numerics;
+
+namespace NiTiS.Math;
+
+/// <summary>
+/// Parses vector components written as <c>&lt;x, y&gt;</c>, <c>(x, y)</c> or <c>x y</c>.
+/// </summary>
+internal static class VectorParser
+{
+	// Group separators split components, so numbers must not contain them
+	private const NumberStyles Styles = NumberStyles.Float;
+
+	/// <summary>Parses exactly <c>components.Length</c> components.</summary>
+	public static bool TryParse<T>(ReadOnlySpan<char> s, IFormatProvider? provider, Span<T> components)
+		where T : unmanaged, INumberBase<T>
+	{
+		s = s.Trim();
+
+		if (s.Length >= 2
+			&& ((s[0] == '<' && s[^1] == '>') || (s[0] == '(' && s[^1] == ')')))
+		{
+			s = s[1..^1].Trim();
+		}
+
+		string groupSeparator = NumberFormatInfo.GetInstance(provider).NumberGroupSeparator;
+
+		// ToString writes the group separator followed by a space, numbers formatted with
+		// group separators never contain that pair, so prefer it over the bare separator
+		ReadOnlySpan<char> separator = ReadOnlySpan<char>.Empty;
+		if (!string.IsNullOrWhiteSpace(groupSeparator))
+		{
+			string spaced = groupSeparator + " ";
+
+			if (s.IndexOf(spaced.AsSpan(), StringComparison.Ordinal) >= 0)
+				separator = spaced;
+			else if (s.IndexOf(groupSeparator.AsSpan(), StringComparison.Ordinal) >= 0)
+				separator = groupSeparator;
+		}
+
+		for (int i = 0; i < components.Length; i++)
+		{
+			ReadOnlySpan<char> component;
+
+			if (i == components.Length - 1)
+			{
+				// Anything still separated is an extra component, not part of the last one
+				int extra = separator.IsEmpty
+					? IndexOfWhiteSpace(s.Trim())
+					: s.IndexOf(separator, StringComparison.Ordinal);
+
+				if (extra >= 0)
+					return false;
+
+				component = s;
+			}
+			else
+			{
+				int index = separator.IsEmpty
+					? IndexOfWhiteSpace(s)
+					: s.IndexOf(separator, StringComparison.Ordinal);
+
+				if (index < 0)
+					return false;
+
+				component = s[..index];
+				s = s[(index + (separator.IsEmpty ? 1 : separator.Length))..].TrimStart();
+			}
+
+			if (!T.TryParse(component.Trim(), Styles, provider, out T value))
+				return false;
+
+			components[i] = value;
+		}
+
+		return true;
+	}
+
+	private static int IndexOfWhiteSpace(ReadOnlySpan<char> s)
+	{
+		for (int i = 0; i < s.Length; i++)
+		{
+			if (char.IsWhiteSpace(s[i]))
+				return i;
+		}
+		return -1;
+	}
+}
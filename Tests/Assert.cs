@@ -0,0 +1,46 @@
+﻿using System;
+using System.Collections.Generic;
+
+namespace NiTiS.Core.Tests;
+
+public sealed class AssertionException : Exception
+{
+	public AssertionException(string message)
+		: base(message) { }
+}
+
+internal static class Assert
+{
+	public static void True(bool condition, string message)
+	{
+		if (!condition)
+			throw new AssertionException(message);
+	}
+
+	public static void Equal<T>(T expected, T actual)
+	{
+		if (!EqualityComparer<T>.Default.Equals(expected, actual))
+			throw new AssertionException($"Expected {expected}, got {actual}");
+	}
+
+	public static void Near(double expected, double actual, double epsilon)
+	{
+		if (!(double.Abs(expected - actual) <= epsilon))
+			throw new AssertionException($"Expected {expected} within {epsilon}, got {actual}");
+	}
+
+	public static void Throws<TException>(Action action)
+		where TException : Exception
+	{
+		try
+		{
+			action();
+		}
+		catch (TException)
+		{
+			return;
+		}
+
+		throw new AssertionException($"Expected {typeof(TException).Name}");
+	}
+}
@@ -0,0 +1,53 @@
+﻿using System;
+using NiTiS.Math;
+
+namespace NiTiS.Core.Tests;
+
+public static class EulerTests
+{
+	private static readonly Tolerance<double> Close = Tolerance.Combined(1e-12, 1e-12);
+
+	public static void RoundTrip()
+	{
+		foreach (EulerOrder order in Enum.GetValues<EulerOrder>())
+		{
+			foreach (EulerFrame frame in Enum.GetValues<EulerFrame>())
+			{
+				Mat4<double> rotation = Mat4.CreateFromEulerAngles(Angle.FromRadians(0.3), Angle.FromRadians(0.8), Angle.FromRadians(-1.2), order, frame);
+
+				AssertRecomposes(rotation, order, frame);
+			}
+		}
+	}
+
+	public static void GimbalLock()
+	{
+		foreach (EulerOrder order in Enum.GetValues<EulerOrder>())
+		{
+			bool proper = order >= EulerOrder.XYX;
+			double[] locks = proper ? new[] { 0.0, 180.0 } : new[] { 90.0, -90.0 };
+
+			foreach (double degrees in locks)
+			{
+				foreach (EulerFrame frame in Enum.GetValues<EulerFrame>())
+				{
+					Mat4<double> rotation = Mat4.CreateFromEulerAngles(Angle.FromRadians(0.3), Angle.FromDegrees(degrees), Angle.FromRadians(0.5), order, frame);
+
+					Mat4.ExtractEulerAngles(rotation, order, frame, out Angle<double> first, out _, out Angle<double> third);
+					double zeroed = frame == EulerFrame.Extrinsic ? third.Radians : first.Radians;
+
+					Assert.Equal(0.0, zeroed);
+					AssertRecomposes(rotation, order, frame);
+				}
+			}
+		}
+	}
+
+	private static void AssertRecomposes(Mat4<double> rotation, EulerOrder order, EulerFrame frame)
+	{
+		Mat4.ExtractEulerAngles(rotation, order, frame, out Angle<double> first, out Angle<double> second, out Angle<double> third);
+		Mat4<double> recomposed = Mat4.CreateFromEulerAngles(first, second, third, order, frame);
+
+		Assert.True(Mat4.ApproximatelyEquals(rotation, recomposed, Close), $"{order} {frame} angles ({first.Radians}, {second.Radians}, {third.Radians}) must rebuild the rotation");
+	}
+}
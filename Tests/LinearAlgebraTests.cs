@@ -0,0 +1,153 @@
+﻿using System;
+using NiTiS.Math;
+using NiTiS.Math.LinearAlgebra;
+
+namespace NiTiS.Core.Tests;
+
+public static class LinearAlgebraTests
+{
+	private static readonly Matrix<double> Regular = new(3, 3, new double[]
+	{
+		4, -2, 1,
+		3, 6, -4,
+		2, 1, 8,
+	});
+	private static readonly Matrix<double> RankTwo = new(3, 3, new double[]
+	{
+		1, 2, 3,
+		4, 5, 6,
+		7, 8, 9,
+	});
+	private static readonly Matrix<double> SymmetricPositiveDefinite = new(3, 3, new double[]
+	{
+		4, 1, 2,
+		1, 5, 3,
+		2, 3, 6,
+	});
+
+	public static void LUSolveRoundTrip()
+	{
+		LUDecomposition<double> lu = Matrix.DecomposeLU(Regular);
+		double[] b = { 1, -2, 3 };
+
+		Assert.True(!lu.IsSingular, "Regular matrix must not be singular");
+		AssertNear(b, Matrix.Multiply(Regular, lu.Solve(b)), 1e-12);
+		AssertNear(Permute(Regular, lu.Pivots), lu.L * lu.U, 1e-12);
+	}
+
+	public static void LUDetectsRoundedSingularity()
+	{
+		LUDecomposition<double> lu = Matrix.DecomposeLU(RankTwo);
+
+		Assert.True(lu.IsSingular, "Rank deficient matrix must be singular despite rounding");
+		Assert.Throws<InvalidOperationException>(() => lu.Solve(new double[] { 1, 2, 3 }));
+	}
+
+	public static void InvertRoundTrip()
+	{
+		Assert.True(Matrix.Invert(Regular, out Matrix<double>? inverse), "Regular matrix must be invertible");
+		AssertNear(Matrix<double>.CreateIdentity(3), Regular * inverse!, 1e-12);
+		Assert.True(!Matrix.Invert(RankTwo, out _), "Rank deficient matrix must not invert");
+	}
+
+	public static void QRLeastSquares()
+	{
+		// Points on y = 1 + 2x, fitted with columns [1, x]
+		Matrix<double> a = new(4, 2, new double[]
+		{
+			1, 0,
+			1, 1,
+			1, 2,
+			1, 3,
+		});
+		QRDecomposition<double> qr = Matrix.DecomposeQR(a);
+
+		Assert.True(qr.IsFullRank, "Independent columns must be full rank");
+		AssertNear(new double[] { 1, 2 }, qr.Solve(new double[] { 1, 3, 5, 7 }), 1e-12);
+		AssertNear(a, qr.Q * qr.R, 1e-12);
+	}
+
+	public static void QRDetectsRoundedRankDeficiency()
+	{
+		QRDecomposition<double> qr = Matrix.DecomposeQR(RankTwo);
+
+		Assert.True(!qr.IsFullRank, "Rank deficient matrix must not be full rank despite rounding");
+		Assert.Throws<InvalidOperationException>(() => qr.Solve(new double[] { 1, 2, 3 }));
+	}
+
+	public static void CholeskyRoundTrip()
+	{
+		CholeskyDecomposition<double> cholesky = Matrix.DecomposeCholesky(SymmetricPositiveDefinite);
+
+		Assert.True(cholesky.IsPositiveDefinite, "Matrix must be positive definite");
+		AssertNear(SymmetricPositiveDefinite, cholesky.L * Matrix.Transpose(cholesky.L), 1e-12);
+		Assert.True(!Matrix.DecomposeCholesky(RankTwo).IsPositiveDefinite, "Indefinite matrix must be rejected");
+	}
+
+	public static void SvdRoundTrip()
+	{
+		Matrix<double> a = new(4, 3, new double[]
+		{
+			2, 0, 1,
+			-1, 3, 2,
+			4, 1, -2,
+			0, 5, 1,
+		});
+		SingularValueDecomposition<double> svd = Matrix.DecomposeSvd(a);
+
+		AssertNear(a, svd.U * svd.S * Matrix.Transpose(svd.V), 1e-12);
+		Assert.Equal(3, svd.Rank);
+		Assert.Equal(2, Matrix.DecomposeSvd(RankTwo).Rank);
+	}
+
+	public static void SymmetricEigenColumns()
+	{
+		SymmetricEigenDecomposition<double> eigen = Matrix.DecomposeSymmetricEigen(SymmetricPositiveDefinite);
+
+		AssertNear(SymmetricPositiveDefinite * eigen.Eigenvectors, eigen.Eigenvectors * eigen.D, 1e-12);
+	}
+
+	public static void Mat3SymmetricEigenColumns()
+	{
+		Mat3<double> a = new(
+			4, 1, 2,
+			1, 5, 3,
+			2, 3, 6
+			);
+		Mat3.DecomposeSymmetricEigen(a, out Vector3D<double> values, out Mat3<double> vectors);
+
+		ReadOnlySpan<Vector3D<double>> columns = stackalloc Vector3D<double>[]
+		{
+			new(vectors.M11, vectors.M21, vectors.M31),
+			new(vectors.M12, vectors.M22, vectors.M32),
+			new(vectors.M13, vectors.M23, vectors.M33),
+		};
+		ReadOnlySpan<double> lambdas = stackalloc double[] { values.X, values.Y, values.Z };
+
+		// The matrix is symmetric, so transforming a row vector equals multiplying the column
+		for (int i = 0; i < 3; i++)
+			Assert.True(Vector3D.ApproximatelyEquals(Vector3D.Transform(columns[i], a), columns[i] * lambdas[i], Tolerance.Combined(1e-12, 1e-12)), $"Column {i} must be an eigenvector");
+	}
+
+	internal static void AssertNear(ReadOnlySpan<double> expected, ReadOnlySpan<double> actual, double epsilon)
+	{
+		Assert.Equal(expected.Length, actual.Length);
+
+		for (int i = 0; i < expected.Length; i++)
+			Assert.Near(expected[i], actual[i], epsilon);
+	}
+	internal static void AssertNear(Matrix<double> expected, Matrix<double> actual, double epsilon)
+	{
+		Assert.Equal(expected.Rows, actual.Rows);
+		Assert.Equal(expected.Columns, actual.Columns);
+		AssertNear(expected.AsSpan(), actual.AsSpan(), epsilon);
+	}
+
+	private static Matrix<double> Permute(Matrix<double> matrix, ReadOnlySpan<int> pivots)
+	{
+		Matrix<double> result = new(matrix.Rows, matrix.Columns);
+		for (int i = 0; i < pivots.Length; i++)
+			matrix.GetRow(pivots[i]).CopyTo(result.GetRow(i));
+		return result;
+	}
+}
@@ -0,0 +1,60 @@
+﻿using NiTiS.Math;
+
+namespace NiTiS.Core.Tests;
+
+public static class Mat4Tests
+{
+	private static readonly Tolerance<double> Close = Tolerance.Combined(1e-12, 1e-12);
+
+	private static readonly Mat4<double> Affine = Mat4.CreateScale(2.0, 3.0, 0.5)
+		* Mat4.CreateFromAxisAngle(Vector3D.Normalize(new Vector3D<double>(1, 2, 3)), Angle.FromRadians(0.7))
+		* Mat4.CreateTranslation(new Vector3D<double>(1, -2, 3));
+	private static readonly Mat4<double> Projective = Mat4.CreatePerspectiveFieldOfView(Angle.FromDegrees(60.0), 1.5, 0.1, 100.0);
+
+	public static void InvertAffineRoundTrip()
+	{
+		Assert.True(Mat4.Invert(Affine, out Mat4<double> inverse), "Affine matrix must be invertible");
+		Assert.True(Mat4.IsApproximatelyIdentity(Affine * inverse, Close), "M * inverse(M) must be identity");
+		Assert.True(Mat4.IsApproximatelyIdentity(inverse * Affine, Close), "inverse(M) * M must be identity");
+	}
+
+	public static void InvertProjectiveRoundTrip()
+	{
+		Assert.True(Mat4.Invert(Projective, out Mat4<double> inverse), "Projection must be invertible");
+		Assert.True(Mat4.IsApproximatelyIdentity(Projective * inverse, Tolerance.Combined(1e-9, 1e-9)), "P * inverse(P) must be identity");
+	}
+
+	public static void InvertSingular()
+	{
+		Assert.True(!Mat4.Invert(Mat4.CreateScale(1.0, 0.0, 1.0), out _), "Flattening scale must be singular");
+	}
+
+	public static void InvertEpsilonIsAbsolute()
+	{
+		// Determinant 1e-9
+		Mat4<double> small = Mat4.CreateScale(1e-3);
+
+		Assert.True(Mat4.Invert(small, out _), "Tiny but regular matrix must invert without epsilon");
+		Assert.True(!Mat4.Invert(small, 1e-6, out _), "Determinant below epsilon must count as singular");
+	}
+
+	public static void DecomposeRoundTrip()
+	{
+		Mat4<double> rotation = Mat4.CreateFromAxisAngle(Vector3D.Normalize(new Vector3D<double>(1, 2, 3)), Angle.FromRadians(0.7));
+
+		Assert.True(Mat4.Decompose(Affine, out Vector3D<double> scale, out Mat4<double> decomposed, out Vector3D<double> translation), "Scale, rotation and translation must decompose");
+		Assert.True(Vector3D.ApproximatelyEquals(scale, new Vector3D<double>(2.0, 3.0, 0.5), Close), $"Unexpected scale {scale}");
+		Assert.True(Mat4.ApproximatelyEquals(decomposed, rotation, Close), "Unexpected rotation");
+		Assert.True(Vector3D.ApproximatelyEquals(translation, new Vector3D<double>(1, -2, 3), Close), $"Unexpected translation {translation}");
+		Assert.True(Mat4.ApproximatelyEquals(Mat4.CreateScale(scale) * decomposed * Mat4.CreateTranslation(translation), Affine, Close), "Recomposition must match");
+	}
+
+	public static void DecomposeReportsMirroring()
+	{
+		Mat4<double> mirrored = Mat4.CreateScale(-1.0, 1.0, 1.0) * Affine;
+		DecompositionFlags flags = Mat4.Decompose(mirrored, 1e-9, out Vector3D<double> scale, out Mat4<double> rotation, out Vector3D<double> translation);
+
+		Assert.Equal(DecompositionFlags.NegativeScale, flags);
+		Assert.True(Mat4.ApproximatelyEquals(Mat4.CreateScale(scale) * rotation * Mat4.CreateTranslation(translation), mirrored, Close), "Recomposition must match");
+	}
+}
@@ -0,0 +1,67 @@
+﻿using NiTiS.Math;
+
+namespace NiTiS.Core.Tests;
+
+public static class Orient2DTests
+{
+	public static void BasicTurns()
+	{
+		Vector2D<double> a = new(0, 0), b = new(1, 0), c = new(0, 1);
+
+		Assert.Equal(Orientation.CounterClockwise, Vector2D.Orient2D(a, b, c));
+		Assert.Equal(Orientation.Clockwise, Vector2D.Orient2D(a, c, b));
+		Assert.Equal(Orientation.Collinear, Vector2D.Orient2D(a, b, new Vector2D<double>(5, 0)));
+		Assert.Equal(Orientation.CounterClockwise, Vector2D.Orient2D<decimal>(new(0, 0), new(1, 0), new(0, 1)));
+	}
+
+	public static void NearCollinearDouble()
+	{
+		// Shewchuk's example, the exact determinant of these points is 12 * (j - i) * 2^-53
+		double ulp = double.ScaleB(1.0, -53);
+		Vector2D<double> b = new(12, 12), c = new(24, 24);
+
+		for (int i = 0; i < 32; i++)
+		{
+			for (int j = 0; j < 32; j++)
+			{
+				Vector2D<double> a = new(0.5 + i * ulp, 0.5 + j * ulp);
+
+				Assert.Equal((Orientation)int.Sign(j - i), Vector2D.Orient2D(a, b, c));
+			}
+		}
+	}
+
+	public static void NearCollinearFloat()
+	{
+		float ulp = float.ScaleB(1f, -24);
+		Vector2D<float> b = new(12, 12), c = new(24, 24);
+
+		for (int i = 0; i < 32; i++)
+		{
+			for (int j = 0; j < 32; j++)
+			{
+				Vector2D<float> a = new(0.5f + i * ulp, 0.5f + j * ulp);
+
+				Assert.Equal((Orientation)int.Sign(j - i), Vector2D.Orient2D(a, b, c));
+			}
+		}
+	}
+
+	public static void IntegersDoNotWrap()
+	{
+		// Products exceed the range of the component type
+		Assert.Equal(Orientation.Collinear, Vector2D.Orient2D<int>(new(0, 0), new(40000, 40001), new(80000, 80002)));
+		Assert.Equal(Orientation.CounterClockwise, Vector2D.Orient2D<int>(new(0, 0), new(40000, 40001), new(80000, 80003)));
+		Assert.Equal(Orientation.Clockwise, Vector2D.Orient2D<int>(new(0, 0), new(40000, 40001), new(80000, 80001)));
+		Assert.Equal(Orientation.CounterClockwise, Vector2D.Orient2D<short>(new(0, 0), new(20000, 20001), new(-20001, 20000)));
+		Assert.Equal(Orientation.CounterClockwise, Vector2D.Orient2D<long>(new(0, 0), new(long.MaxValue / 2, 1), new(1, long.MaxValue / 2)));
+	}
+
+	public static void NaNIsCollinear()
+	{
+		Vector2D<double> a = new(0, 0), b = new(1, 0);
+
+		Assert.Equal(Orientation.Collinear, Vector2D.Orient2D(a, b, new Vector2D<double>(double.NaN, 1)));
+		Assert.Equal(Orientation.Collinear, Vector2D.Orient2D<float>(new(0, 0), new(1, 0), new(1, float.NaN)));
+	}
+}
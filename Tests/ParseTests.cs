@@ -0,0 +1,53 @@
+﻿using System.Globalization;
+using NiTiS.Math;
+
+namespace NiTiS.Core.Tests;
+
+public static class ParseTests
+{
+	private static readonly CultureInfo[] Cultures =
+	{
+		CultureInfo.InvariantCulture,
+		CultureInfo.GetCultureInfo("en-US"),
+		CultureInfo.GetCultureInfo("de-DE"),
+		CultureInfo.GetCultureInfo("fr-FR"),
+	};
+
+	public static void RoundTripAcrossCultures()
+	{
+		Vector3D<double> vector = new(1234.5, -0.125, 1e-7);
+		Vector2D<int> integers = new(12345, -678);
+
+		foreach (CultureInfo culture in Cultures)
+		{
+			Assert.Equal(vector, Vector3D<double>.Parse(vector.ToString("G", culture), culture));
+			Assert.Equal(integers, Vector2D<int>.Parse(integers.ToString("G", culture), culture));
+		}
+	}
+
+	public static void AcceptsAlternativeForms()
+	{
+		CultureInfo culture = CultureInfo.InvariantCulture;
+		Vector2D<double> expected = new(1.5, -2);
+
+		Assert.Equal(expected, Vector2D<double>.Parse("(1.5, -2)", culture));
+		Assert.Equal(expected, Vector2D<double>.Parse("1.5 -2", culture));
+		Assert.Equal(expected, Vector2D<double>.Parse("  <1.5,-2>  ", culture));
+	}
+
+	public static void RejectsExtraComponents()
+	{
+		CultureInfo culture = CultureInfo.InvariantCulture;
+
+		Assert.True(!Vector2D<int>.TryParse("<1,2,3>", culture, out _), "Third component must be rejected");
+		Assert.True(!Vector2D<int>.TryParse("<1, 2, 3>", culture, out _), "Third component must be rejected");
+		Assert.True(!Vector2D<int>.TryParse("1 2 3", culture, out _), "Third component must be rejected");
+		Assert.True(!Vector3D<double>.TryParse("<1, 2>", culture, out _), "Missing component must be rejected");
+	}
+
+	public static void RejectsGroupSeparators()
+	{
+		Assert.True(!Vector2D<int>.TryParse("<1,000, 2>", CultureInfo.InvariantCulture, out _), "Grouped number must not swallow a separator");
+		Assert.True(!Vector2D<double>.TryParse("<1.000; 2>", CultureInfo.GetCultureInfo("de-DE"), out _), "Unknown separator must be rejected");
+	}
+}
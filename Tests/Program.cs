@@ -1,11 +1,39 @@
-﻿using NiTiS.Math;
+﻿using System;
+using System.Linq;
+using System.Reflection;
 
 namespace NiTiS.Core.Tests;
 
 public static class Program
 {
-	public static unsafe void Main(string[] args)
+	/// <summary>Runs every public parameterless method of the <c>*Tests</c> classes, arguments filter tests by name.</summary>
+	public static int Main(string[] args)
 	{
-		Vector2D<int>* vec = null; 
+		int passed = 0, failed = 0;
+
+		foreach (Type type in typeof(Program).Assembly.GetTypes().Where(type => type.Name.EndsWith("Tests", StringComparison.Ordinal)).OrderBy(type => type.Name))
+		{
+			foreach (MethodInfo method in type.GetMethods(BindingFlags.Public | BindingFlags.Static).Where(method => method.GetParameters().Length == 0 && method.ReturnType == typeof(void)))
+			{
+				string name = $"{type.Name}.{method.Name}";
+
+				if (args.Length > 0 && !args.Any(filter => name.Contains(filter, StringComparison.OrdinalIgnoreCase)))
+					continue;
+
+				try
+				{
+					method.Invoke(null, null);
+					passed++;
+				}
+				catch (TargetInvocationException e)
+				{
+					failed++;
+					Console.WriteLine($"FAIL {name}: {e.InnerException?.Message}");
+				}
+			}
+		}
+
+		Console.WriteLine($"{passed} passed, {failed} failed");
+		return failed == 0 ? 0 : 1;
 	}
 }
@@ -0,0 +1,94 @@
+﻿using System;
+using NiTiS.Math;
+
+namespace NiTiS.Core.Tests;
+
+public static class SoATests
+{
+	// Not a multiple of any vector width, so the scalar tail runs too
+	private const int Count = 37;
+
+	private static readonly Tolerance<float> Close = Tolerance.Combined(1e-5f, 1e-5f);
+	private static readonly Mat4<float> Matrix = Mat4.CreateScale(2f, 0.5f, 3f)
+		* Mat4.CreateFromAxisAngle(Vector3D.Normalize(new Vector3D<float>(1, -2, 0.5f)), Angle.FromRadians(1.1f))
+		* Mat4.CreateTranslation(new Vector3D<float>(4, -1, 2));
+
+	public static void AddParity()
+	{
+		Vector3D<float>[] left = CreateVectors(1), right = CreateVectors(2);
+		Vector3D<float>[] expected = new Vector3D<float>[Count];
+		Vector3DSoA<float> actual = new(Count);
+
+		Vector3D.Add<float>(left, right, expected);
+		Vector3DSoA.Add((Vector3DSoA<float>)left, (Vector3DSoA<float>)right, actual);
+
+		AssertParity(expected, actual);
+	}
+
+	public static void DotParity()
+	{
+		Vector3D<float>[] left = CreateVectors(3), right = CreateVectors(4);
+		float[] expected = new float[Count], actual = new float[Count];
+
+		Vector3D.Dot<float>(left, right, expected);
+		Vector3DSoA.Dot((Vector3DSoA<float>)left, (Vector3DSoA<float>)right, actual);
+
+		for (int i = 0; i < Count; i++)
+			Assert.True(Close.AreEqual(expected[i], actual[i]), $"Dot {i}: expected {expected[i]}, got {actual[i]}");
+	}
+
+	public static void NormalizeParity()
+	{
+		Vector3D<float>[] source = CreateVectors(5);
+		Vector3D<float>[] expected = new Vector3D<float>[Count];
+		Vector3DSoA<float> actual = (Vector3DSoA<float>)source;
+
+		Vector3D.Normalize<float>(source, expected);
+		Vector3DSoA.Normalize(actual, actual);
+
+		AssertParity(expected, actual);
+	}
+
+	public static void TransformParity()
+	{
+		Vector3D<float>[] source = CreateVectors(6);
+		Vector3D<float>[] expected = new Vector3D<float>[Count];
+		Vector3DSoA<float> actual = (Vector3DSoA<float>)source;
+
+		Vector3D.Transform<float>(source, Matrix, expected);
+		Vector3DSoA.Transform(actual, Matrix, actual);
+
+		AssertParity(expected, actual);
+
+		actual = (Vector3DSoA<float>)source;
+
+		Vector3D.TransformNormal<float>(source, Matrix, expected);
+		Vector3DSoA.TransformNormal(actual, Matrix, actual);
+
+		AssertParity(expected, actual);
+	}
+
+	public static void CountMismatchThrows()
+	{
+		Assert.Throws<ArgumentException>(() => Vector3DSoA.Add(new Vector3DSoA<float>(2), new Vector3DSoA<float>(3), new Vector3DSoA<float>(2)));
+	}
+
+	private static Vector3D<float>[] CreateVectors(int seed)
+	{
+		Random random = new(seed);
+		Vector3D<float>[] vectors = new Vector3D<float>[Count];
+
+		for (int i = 0; i < Count; i++)
+			vectors[i] = new(random.NextSingle() * 20 - 10, random.NextSingle() * 20 - 10, random.NextSingle() * 20 - 10);
+
+		return vectors;
+	}
+
+	private static void AssertParity(Vector3D<float>[] expected, Vector3DSoA<float> actual)
+	{
+		Assert.Equal(expected.Length, actual.Count);
+
+		for (int i = 0; i < expected.Length; i++)
+			Assert.True(Vector3D.ApproximatelyEquals(expected[i], actual[i], Close), $"Vector {i}: expected {expected[i]}, got {actual[i]}");
+	}
+}
@@ -0,0 +1,56 @@
+﻿using System;
+using NiTiS.Math;
+
+namespace NiTiS.Core.Tests;
+
+public static class ToleranceTests
+{
+	public static void UlpsAcrossZero()
+	{
+		Assert.True(Tolerance.FromUlps<double>(0).AreEqual(0.0, -0.0), "Both zeros must be zero ulps apart");
+		Assert.True(Tolerance.FromUlps<double>(1).AreEqual(-0.0, double.Epsilon), "Smallest subnormal must be one ulp from negative zero");
+		Assert.True(Tolerance.FromUlps<double>(2).AreEqual(-double.Epsilon, double.Epsilon), "Opposite subnormals must be two ulps apart");
+		Assert.True(!Tolerance.FromUlps<double>(1).AreEqual(-double.Epsilon, double.Epsilon), "Opposite subnormals must not be one ulp apart");
+		Assert.True(Tolerance.FromUlps<float>(2).AreEqual(-float.Epsilon, float.Epsilon), "Opposite float subnormals must be two ulps apart");
+		Assert.True(Tolerance.FromUlps<Half>(2).AreEqual(-Half.Epsilon, Half.Epsilon), "Opposite half subnormals must be two ulps apart");
+	}
+
+	public static void UlpsAcrossExponent()
+	{
+		Tolerance<double> one = Tolerance.FromUlps<double>(1);
+
+		Assert.True(one.AreEqual(1.0, double.BitIncrement(1.0)), "Next value must be one ulp away");
+		Assert.True(one.AreEqual(1.0, double.BitDecrement(1.0)), "Previous value across the exponent boundary must be one ulp away");
+		Assert.True(!one.AreEqual(double.BitDecrement(1.0), double.BitIncrement(1.0)), "Neighbours of one must be two ulps apart");
+	}
+
+	public static void UlpsDoNotOverflow()
+	{
+		Tolerance<double> widest = Tolerance.FromUlps<double>(int.MaxValue);
+
+		Assert.True(!widest.AreEqual(-double.MaxValue, double.MaxValue), "Opposite extremes must not wrap around");
+		Assert.True(!widest.AreEqual(double.MaxValue, double.PositiveInfinity), "Infinity must not be close to a finite value");
+		Assert.True(widest.AreEqual(double.NegativeInfinity, double.NegativeInfinity), "Equal infinities must be equal");
+		Assert.True(!widest.AreEqual(double.NaN, double.NaN), "NaN must not equal itself");
+	}
+
+	public static void CombinedNearZero()
+	{
+		Tolerance<double> relative = Tolerance.Relative(1e-9);
+		Tolerance<double> combined = Tolerance.Combined(1e-12, 1e-9);
+
+		Assert.True(!relative.AreEqual(0.0, 1e-300), "Only zero must be relatively close to zero");
+		Assert.True(combined.AreEqual(0.0, 1e-300), "Absolute floor must accept values near zero");
+		Assert.True(!combined.AreEqual(0.0, 1e-11), "Absolute floor must stay tight near zero");
+		Assert.True(combined.AreEqual(1e9, 1e9 + 0.5), "Relative part must scale with magnitude");
+		Assert.True(!Tolerance.Absolute(1e-12).AreEqual(1e9, 1e9 + 0.5), "Absolute tolerance must not scale");
+	}
+
+	public static void RejectsInvalidEpsilon()
+	{
+		Assert.Throws<ArgumentOutOfRangeException>(() => Tolerance.Absolute(-1.0));
+		Assert.Throws<ArgumentOutOfRangeException>(() => Tolerance.Relative(double.NaN));
+		Assert.Throws<ArgumentOutOfRangeException>(() => Tolerance.Combined(1e-12, -1e-9));
+		Assert.Throws<ArgumentOutOfRangeException>(() => Tolerance.FromUlps<double>(-1));
+	}
+}